@@ -0,0 +1,53 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use grapple_utils::cuuid::CUuid;
+use uuid::Uuid;
+
+fn bench_encode(c: &mut Criterion) {
+    let id = Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap();
+
+    for (name, cuuid) in [
+        ("b32", CUuid::B32),
+        ("b58", CUuid::B58),
+        ("b64", CUuid::B64),
+    ] {
+        let mut group = c.benchmark_group(format!("encode_{name}"));
+
+        // Allocates a `String` per call
+        group.bench_function("string", |b| {
+            b.iter(|| cuuid.scheme().encode(black_box(id.as_bytes())))
+        });
+
+        // Stack allocated `EncodedId`
+        group.bench_function("encoded_id", |b| b.iter(|| cuuid.from(black_box(id))));
+
+        group.finish();
+    }
+}
+
+fn bench_encode_many(c: &mut Criterion) {
+    let ids: Vec<Uuid> = (0..1000).map(|_| Uuid::now_v7()).collect();
+    let cuuid = CUuid::B58;
+
+    let mut group = c.benchmark_group("encode_many_b58");
+
+    // Allocates a `String` per id
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            black_box(&ids)
+                .iter()
+                .map(|id| cuuid.from(*id).into_string())
+                .collect::<Vec<String>>()
+        })
+    });
+
+    // Reuses a single buffer
+    let mut batch = grapple_utils::cuuid::EncodedBatch::new();
+    group.bench_function("batch", |b| {
+        b.iter(|| cuuid.encode_many_into(black_box(&ids), &mut batch))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_encode_many);
+criterion_main!(benches);
@@ -0,0 +1,53 @@
+// This is config example, than uses the `envs` and `derive` features.
+
+#[cfg(all(feature = "envs", feature = "derive"))]
+use grapple_utils::envs::FromEnv;
+#[cfg(all(feature = "envs", feature = "derive"))]
+use std::collections::HashMap;
+
+#[cfg(all(feature = "envs", feature = "derive"))]
+pub fn config() -> &'static Config {
+    use std::sync::OnceLock;
+    static INSTANCE: OnceLock<Config> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| {
+        Config::load_from_env()
+            .unwrap_or_else(|ex| panic!("FATAL - WHOLE LOADING CONF - Cause: {ex:?}"))
+    })
+}
+
+#[cfg(all(feature = "envs", feature = "derive"))]
+#[derive(Debug, FromEnv)]
+pub struct Config {
+    pub string: String,
+    #[env(default = "0")]
+    pub number: f64,
+    #[env(keys)]
+    pub keys: HashMap<String, String>,
+    #[cfg(feature = "b64")]
+    #[env(name = "B64", encoding = "b64u", bytes)]
+    pub secret: Vec<u8>, // Useful for secret keys
+    #[cfg(feature = "b58")]
+    #[env(encoding = "b58", optional)]
+    pub b58: Option<String>,
+}
+
+#[cfg(not(all(feature = "envs", feature = "derive")))]
+fn main() {
+    panic!("This example requires 'envs' and 'derive' features to be enabled.");
+}
+
+#[cfg(all(feature = "envs", feature = "derive"))]
+fn main() {
+    println!("STRING: {}", config().string);
+
+    println!("NUMBER: {}", config().number);
+
+    println!("KEYS: {:?}", config().keys);
+
+    #[cfg(feature = "b64")]
+    println!("B64: {}", config().secret.len());
+
+    #[cfg(feature = "b58")]
+    println!("B58 decoded: {:?}", config().b58);
+}
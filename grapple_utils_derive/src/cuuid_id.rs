@@ -0,0 +1,160 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, Member, Result};
+
+/// Options from the `#[cuuid(...)]` attribute.
+struct Options {
+    scheme: Ident,
+    strategy: Ident,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> Result<Options> {
+        let mut scheme = Ident::new("B64", proc_macro2::Span::call_site());
+        let mut strategy = Ident::new("now_v7", proc_macro2::Span::call_site());
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("cuuid")) {
+            attr.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+
+                let name = match value.value().to_ascii_lowercase().as_str() {
+                    "b58" if meta.path.is_ident("scheme") => "B58",
+                    "b64" if meta.path.is_ident("scheme") => "B64",
+                    "b32" if meta.path.is_ident("scheme") => "B32",
+                    "v7" if meta.path.is_ident("strategy") => "now_v7",
+                    "v4" if meta.path.is_ident("strategy") => "new_v4",
+                    _ if meta.path.is_ident("scheme") => {
+                        return Err(meta.error("expected `b58`, `b64` or `b32`"))
+                    }
+                    _ if meta.path.is_ident("strategy") => {
+                        return Err(meta.error("expected `v7` or `v4`"))
+                    }
+                    _ => return Err(meta.error("expected `scheme` or `strategy`")),
+                };
+
+                let ident = Ident::new(name, value.span());
+                match meta.path.is_ident("scheme") {
+                    true => scheme = ident,
+                    false => strategy = ident,
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(Options { scheme, strategy })
+    }
+}
+
+/// Returns the member of the single field of the struct.
+fn single_field(input: &DeriveInput) -> Result<Member> {
+    let error = || syn::Error::new_spanned(&input.ident, "expected a struct with a single field");
+
+    let Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+
+    match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => Ok(Member::Named(
+            fields.named[0].ident.clone().ok_or_else(error)?,
+        )),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(Member::Unnamed(0.into())),
+        _ => Err(error()),
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Options { scheme, strategy } = Options::parse(&input)?;
+    let member = single_field(&input)?;
+
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic ids are not supported",
+        ));
+    }
+
+    let cuuid = quote!(::grapple_utils::cuuid);
+    let construct = match &member {
+        Member::Named(ident) => quote!(Self { #ident: id }),
+        Member::Unnamed(_) => quote!(Self(id)),
+    };
+    let member = member.into_token_stream();
+
+    let serde = cfg!(feature = "serde").then(|| {
+        quote! {
+            impl #cuuid::__private::serde::Serialize for #name {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: #cuuid::__private::serde::Serializer,
+                {
+                    serializer.serialize_str(&Self::CUUID.from(self.#member))
+                }
+            }
+
+            impl<'de> #cuuid::__private::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: #cuuid::__private::serde::Deserializer<'de>,
+                {
+                    let value = <::std::string::String as #cuuid::__private::serde::Deserialize>::deserialize(deserializer)?;
+                    ::core::str::FromStr::from_str(&value)
+                        .map_err(<D::Error as #cuuid::__private::serde::de::Error>::custom)
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[allow(clippy::new_without_default)]
+        impl #name {
+            /// Scheme used to encode the id.
+            pub const CUUID: #cuuid::CUuid = #cuuid::CUuid::#scheme;
+
+            /// Generates a new id.
+            #[inline]
+            pub fn new() -> Self {
+                let id = #cuuid::__private::#strategy();
+                #construct
+            }
+
+            /// Returns the underlying UUID.
+            #[inline]
+            pub fn as_uuid(&self) -> &#cuuid::uuid::Uuid {
+                &self.#member
+            }
+        }
+
+        impl ::core::convert::From<#cuuid::uuid::Uuid> for #name {
+            #[inline]
+            fn from(id: #cuuid::uuid::Uuid) -> Self {
+                #construct
+            }
+        }
+
+        impl ::core::convert::From<#name> for #cuuid::uuid::Uuid {
+            #[inline]
+            fn from(id: #name) -> Self {
+                id.#member
+            }
+        }
+
+        impl ::core::fmt::Display for #name {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                fmt.pad(&Self::CUUID.from(self.#member))
+            }
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = #cuuid::Error;
+
+            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                let id = Self::CUUID.try_decode(value)?;
+                ::core::result::Result::Ok(#construct)
+            }
+        }
+
+        #serde
+    })
+}
@@ -0,0 +1,215 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Field, Fields, Ident, LitStr, Result};
+
+/// Default value of a missing variable.
+enum DefaultValue {
+    /// `Default::default()`
+    Trait,
+    /// Value parsed with `FromStr`.
+    Parsed(LitStr),
+}
+
+/// Options from the `#[env(...)]` attribute of a field.
+struct FieldOptions {
+    name: LitStr,
+    default: Option<DefaultValue>,
+    encoding: Option<Ident>,
+    bytes: bool,
+    keys: bool,
+    optional: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &Field, ident: &Ident) -> Result<FieldOptions> {
+        let mut options = FieldOptions {
+            name: LitStr::new(&ident.unraw().to_string().to_uppercase(), ident.span()),
+            default: None,
+            encoding: None,
+            bytes: false,
+            keys: false,
+            optional: false,
+        };
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("env")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    options.name = meta.value()?.parse()?;
+                } else if meta.path.is_ident("default") {
+                    options.default = match meta.input.peek(syn::Token![=]) {
+                        true => Some(DefaultValue::Parsed(meta.value()?.parse()?)),
+                        false => Some(DefaultValue::Trait),
+                    };
+                } else if meta.path.is_ident("encoding") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    match value.value().as_str() {
+                        "b32" | "b58" | "b64u" => {
+                            options.encoding = Some(Ident::new(&value.value(), value.span()))
+                        }
+                        _ => return Err(meta.error("expected `b32`, `b58` or `b64u`")),
+                    }
+                } else if meta.path.is_ident("bytes") {
+                    options.bytes = true;
+                } else if meta.path.is_ident("keys") {
+                    options.keys = true;
+                } else if meta.path.is_ident("optional") {
+                    options.optional = true;
+                } else {
+                    return Err(meta.error(
+                        "expected `name`, `default`, `encoding`, `bytes`, `keys` or `optional`",
+                    ));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if options.bytes && options.encoding.is_none() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`bytes` can be used only with `encoding`",
+            ));
+        }
+        if options.optional && options.default.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`optional` and `default` can not be used together",
+            ));
+        }
+        if matches!(options.default, Some(DefaultValue::Parsed(_)))
+            && (options.keys || options.encoding.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "default value is supported only for parsed fields, use `default` without a value",
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
+/// Returns the reader parameter of `load_from`, hygienic so fields may have the same name.
+fn reader() -> Ident {
+    Ident::new("envs", Span::mixed_site())
+}
+
+/// Returns the expression reading the field with the `envs` getters into a `Result`.
+fn getter(options: &FieldOptions) -> TokenStream {
+    let envs = quote!(::grapple_utils::envs);
+    let reader = reader();
+    let name = &options.name;
+
+    let function = match (&options.encoding, options.keys) {
+        (None, false) => Ident::new("get_parse", Span::call_site()),
+        (None, true) => Ident::new("get_keys_parse", Span::call_site()),
+        (Some(encoding), keys) => {
+            let keys = if keys { "keys_" } else { "" };
+            let target = if options.bytes { "u8s" } else { "s" };
+            Ident::new(
+                &format!("get_{keys}{encoding}_as_{target}"),
+                encoding.span(),
+            )
+        }
+    };
+
+    let value = quote!(#reader.#function(#name));
+
+    match (&options.default, options.optional) {
+        (_, true) => {
+            let function = Ident::new(&format!("{function}_opt"), function.span());
+            quote!(#reader.#function(#name))
+        }
+        (Some(default), _) => {
+            let default = match default {
+                DefaultValue::Trait => {
+                    quote!(::core::result::Result::Ok(
+                        ::core::default::Default::default()
+                    ))
+                }
+                DefaultValue::Parsed(value) => quote! {
+                    #value.parse().map_err(|_| #envs::Error::WrongFormat(#reader.full_name(#name)))
+                },
+            };
+
+            quote! {
+                match #value {
+                    ::core::result::Result::Err(#envs::Error::MissingEnv(_)) => #default,
+                    value => value,
+                }
+            }
+        }
+        (None, false) => value,
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let error = || syn::Error::new_spanned(name, "expected a struct with named fields");
+
+    let Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(error());
+    };
+
+    let reader = reader();
+    let collector = Ident::new("collector", Span::mixed_site());
+    let mut reads = Vec::new();
+    let mut inits = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().ok_or_else(error)?;
+        let options = FieldOptions::parse(field, ident)?;
+        let getter = getter(&options);
+
+        // Keep `cfg` of fields, e.g. for encodings enabled by features
+        let cfgs: Vec<_> = field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("cfg"))
+            .collect();
+
+        // Locals are hygienic, so fields may be named as them, e.g. `envs`
+        let local = format_ident!("field_{}", ident, span = Span::mixed_site());
+
+        // Every field is read even if previous ones failed, to report all errors
+        reads.push(quote! {
+            #(#cfgs)*
+            let #local = #collector.check(#getter);
+        });
+        inits.push(quote! {
+            #(#cfgs)*
+            #ident: #local.expect("errors are reported by the collector")
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Loads the struct from environment variables.
+            #[inline]
+            pub fn load_from_env() -> ::grapple_utils::envs::Result<Self> {
+                Self::load_from(&::grapple_utils::envs::Envs::global())
+            }
+
+            /// Loads the struct from variables of the reader.
+            ///
+            /// Reports all invalid variables at once in `Error::Multiple`.
+            pub fn load_from(#reader: &::grapple_utils::envs::Envs) -> ::grapple_utils::envs::Result<Self> {
+                let mut #collector = ::grapple_utils::envs::Collector::new();
+
+                #(#reads)*
+
+                #collector.finish()?;
+
+                ::core::result::Result::Ok(Self {
+                    #(#inits,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,49 @@
+//! Derive macros for `grapple_utils`, use them through the `derive` feature of `grapple_utils`.
+
+mod cuuid_id;
+mod from_env;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives a strongly typed entity id backed by `CUuid`.
+///
+/// The struct must have a single `Uuid` field. Generates `new()`, `Display`
+/// and `FromStr` with the encoded representation, conversions from and into
+/// `Uuid`, and `Serialize`/`Deserialize` as a string when the `serde`
+/// feature is enabled.
+///
+/// # Attributes
+/// * `#[cuuid(scheme = "b64")]` - One of `b58`, `b64` or `b32`, `b64` by default.
+/// * `#[cuuid(strategy = "v7")]` - Generation strategy of `new()`, `v7` or `v4`, `v7` by default.
+#[proc_macro_derive(CUuidId, attributes(cuuid))]
+pub fn derive_cuuid_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    cuuid_id::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `load_from_env()` and `load_from(&Envs)` for a configuration struct with named fields.
+///
+/// Every field is read with the `envs` getters from a variable named
+/// as the field in uppercase, `cfg` attributes of fields are kept.
+/// All invalid variables are reported together in `envs::Error::Multiple`.
+///
+/// # Attributes
+/// * `#[env(name = "PORT")]` - Name of the environment variable.
+/// * `#[env(default = "8080")]` - Value parsed when the variable is missing, or
+///   `#[env(default)]` for `Default::default()`.
+/// * `#[env(encoding = "b64u")]` - Decodes the value from `b32`, `b58` or `b64u`
+///   into a `String`, or into a `Vec<u8>` with `#[env(encoding = "b64u", bytes)]`.
+/// * `#[env(keys)]` - Reads `key:value` pairs into a `HashMap`.
+/// * `#[env(optional)]` - Reads into an `Option`, `None` if the variable is missing.
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn derive_from_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_env::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -35,6 +35,11 @@ run_tests "cuuid,b58"
 run_tests "cuuid,b64"
 run_tests "cuuid,b32,b58,b64"
 
+# Derive
+run_tests "derive,cuuid,b58,b64"
+run_tests "derive,serde,cuuid,b58,b64"
+run_tests "derive,envs,b58,b64"
+
 # Test all
 echo "Running tests with all features"
 cargo test --all-features
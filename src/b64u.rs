@@ -12,6 +12,21 @@ pub fn encode(content: impl AsRef<[u8]>) -> String {
     base_x::encode(content, ENCODING)
 }
 
+/// Encodes data into the provided buffer as a Base64 URL-safe string without padding.
+///
+/// # Parameters
+/// - `content`: The data to be encoded. Can be any type that implements `AsRef<[u8]>`.
+/// - `output`: The buffer to write the encoded data into.
+///
+/// # Returns
+/// The number of bytes written into `output`.
+///
+/// # Panics
+/// This will panic if `output` is too small for the encoded data.
+pub fn encode_mut(content: impl AsRef<[u8]>, output: &mut [u8]) -> usize {
+    base_x::encode_mut(content, ENCODING, output)
+}
+
 /// Decodes a Base64 URL-safe string without padding into a vector of bytes.
 ///
 /// # Parameters
@@ -59,6 +74,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encode_mut() -> Result<()> {
+        let mut output = [0u8; 64];
+        let len = encode_mut(TEXT, &mut output);
+        assert_eq!(&output[..len], RESULT.as_bytes());
+
+        Ok(())
+    }
+
     #[test]
     fn test_encode() -> Result<()> {
         let encoded = encode(&TEXT);
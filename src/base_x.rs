@@ -12,6 +12,25 @@ pub fn encode(content: impl AsRef<[u8]>, encoding: Encoding) -> String {
     encoding.encode(content.as_ref())
 }
 
+/// Encodes data into the provided buffer using the specified encoding method.
+///
+/// # Parameters
+/// - `content`: The data to be encoded. Can be any type that implements `AsRef<[u8]>`.
+/// - `encoding`: The BaseX encoding method to be used.
+/// - `output`: The buffer to write the encoded data into.
+///
+/// # Returns
+/// The number of bytes written into `output`.
+///
+/// # Panics
+/// This will panic if `output` is too small for the encoded data.
+pub fn encode_mut(content: impl AsRef<[u8]>, encoding: Encoding, output: &mut [u8]) -> usize {
+    let content = content.as_ref();
+    let len = encoding.encode_len(content.len());
+    encoding.encode_mut(content, &mut output[..len]);
+    len
+}
+
 /// Decodes a BaseX string into a vector of bytes using the specified decoding method.
 ///
 /// # Parameters
@@ -0,0 +1,236 @@
+use uuid::Uuid;
+
+use super::{decode_with, CUuid, Error, Result};
+
+/// Encoded ids written one after another into a single string.
+///
+/// Ids are accessed by index, the underlying buffers are reused
+/// by `CUuid::encode_many_into`.
+#[derive(Debug, Clone, Default)]
+pub struct EncodedBatch {
+    buf: String,
+    ends: Vec<usize>,
+}
+
+impl EncodedBatch {
+    /// Creates an empty batch.
+    pub fn new() -> EncodedBatch {
+        EncodedBatch::default()
+    }
+
+    /// Returns the number of encoded ids.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the batch contains no ids.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the encoded id at `index`.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)?;
+        Some(&self.buf[self.start(index)..end])
+    }
+
+    /// Returns all encoded ids concatenated.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.buf
+    }
+
+    /// Returns the start and end offsets of the id at `index` in `as_str`.
+    pub fn offsets(&self, index: usize) -> Option<(usize, usize)> {
+        let end = *self.ends.get(index)?;
+        Some((self.start(index), end))
+    }
+
+    /// Iterates over the encoded ids.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// Removes all ids, keeping allocated memory.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.ends.clear();
+    }
+
+    #[inline]
+    fn start(&self, index: usize) -> usize {
+        match index {
+            0 => 0,
+            _ => self.ends[index - 1],
+        }
+    }
+}
+
+impl CUuid {
+    /// Encodes many ids into a single batch.
+    ///
+    /// # Arguments
+    /// * `ids` - The ids to encode.
+    pub fn encode_many(&self, ids: &[Uuid]) -> EncodedBatch {
+        let mut batch = EncodedBatch::new();
+        self.encode_many_into(ids, &mut batch);
+        batch
+    }
+
+    /// Encodes many ids into an existing batch, reusing its buffers.
+    ///
+    /// The batch is cleared before encoding.
+    ///
+    /// # Arguments
+    /// * `ids` - The ids to encode.
+    /// * `batch` - The batch to write encoded ids into.
+    pub fn encode_many_into(&self, ids: &[Uuid], batch: &mut EncodedBatch) {
+        let scheme = self.scheme();
+
+        batch.clear();
+        batch.ends.reserve(ids.len());
+
+        for id in ids {
+            batch.buf.push_str(&scheme.encode_id(id.as_bytes()));
+            batch.ends.push(batch.buf.len());
+        }
+    }
+
+    /// Decodes many encoded strings.
+    ///
+    /// # Arguments
+    /// * `values` - The encoded strings, e.g. `EncodedBatch::iter`.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<uuid::Uuid>)` if every value is decoded.
+    /// * `Err(Error::Batch)` with index and error of every failed value.
+    pub fn decode_many<T: AsRef<str>>(
+        &self,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<Vec<Uuid>> {
+        let mut ids = Vec::new();
+        self.decode_many_into(values, &mut ids)?;
+        Ok(ids)
+    }
+
+    /// Decodes many encoded strings into an existing vector, reusing its memory.
+    ///
+    /// The vector is cleared before decoding. On failure it is left empty,
+    /// so positions of ids always match positions of the values.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every value is decoded.
+    /// * `Err(Error::Batch)` with index and error of every failed value.
+    pub fn decode_many_into<T: AsRef<str>>(
+        &self,
+        values: impl IntoIterator<Item = T>,
+        ids: &mut Vec<Uuid>,
+    ) -> Result<()> {
+        let scheme = self.scheme();
+        let mut errors = Vec::new();
+
+        ids.clear();
+
+        for (index, value) in values.into_iter().enumerate() {
+            match decode_with(scheme, value.as_ref()) {
+                Ok(id) => ids.push(id),
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => {
+                ids.clear();
+                Err(Error::Batch(errors))
+            }
+        }
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "b64")]
+    const C: CUuid = CUuid::B64;
+    #[cfg(all(feature = "b58", not(feature = "b64")))]
+    const C: CUuid = CUuid::B58;
+    #[cfg(all(feature = "b32", not(any(feature = "b58", feature = "b64"))))]
+    const C: CUuid = CUuid::B32;
+
+    fn get_ids() -> Vec<Uuid> {
+        (0..10).map(|_| Uuid::now_v7()).collect()
+    }
+
+    #[test]
+    fn test_encode_many() -> Result<()> {
+        let ids = get_ids();
+
+        let batch = C.encode_many(&ids);
+
+        assert_eq!(batch.len(), ids.len());
+        for (index, id) in ids.iter().enumerate() {
+            let (start, end) = batch.offsets(index).unwrap();
+            assert_eq!(batch.get(index), Some(C.from(*id).as_str()));
+            assert_eq!(&batch.as_str()[start..end], C.from(*id).as_str());
+        }
+        assert_eq!(batch.get(ids.len()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_many() -> Result<()> {
+        let ids = get_ids();
+
+        let batch = C.encode_many(&ids);
+        let decoded = C.decode_many(batch.iter())?;
+
+        assert_eq!(decoded, ids);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reuse_buffers() -> Result<()> {
+        let mut batch = EncodedBatch::new();
+        let mut decoded = Vec::new();
+
+        for _ in 0..3 {
+            let ids = get_ids();
+
+            C.encode_many_into(&ids, &mut batch);
+            C.decode_many_into(batch.iter(), &mut decoded)?;
+
+            assert_eq!(decoded, ids);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_many_errors() {
+        let valid = C.now_v7();
+
+        let result = C.decode_many([valid.as_str(), "", "!", valid.as_str()]);
+
+        let Err(Error::Batch(errors)) = result else {
+            panic!("Expected batch error");
+        };
+        let indexes: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indexes, [1, 2]);
+
+        let mut decoded = vec![Uuid::nil()];
+        assert!(C
+            .decode_many_into([valid.as_str(), "!"], &mut decoded)
+            .is_err());
+        assert!(decoded.is_empty());
+    }
+}
+
+// endregion: --- Tests
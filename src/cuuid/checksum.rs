@@ -0,0 +1,102 @@
+//! Luhn mod N check character over the alphabet of a scheme.
+//!
+//! Detects every single character substitution and most transpositions
+//! of adjacent characters. Positions are counted in characters, so
+//! alphabets of custom schemes may contain non-ASCII characters.
+
+/// Computes the check character for `value`.
+///
+/// Returns `None` if `value` contains characters outside of `alphabet`.
+pub(super) fn generate(value: &str, alphabet: &str) -> Option<char> {
+    let n = alphabet.chars().count();
+    let mut factor = 2;
+    let mut sum = 0;
+
+    for c in value.chars().rev() {
+        let addend = factor * code_point(alphabet, c)?;
+        factor = if factor == 2 { 1 } else { 2 };
+        sum += addend / n + addend % n;
+    }
+
+    let check = (n - sum % n) % n;
+    alphabet.chars().nth(check)
+}
+
+/// Checks that the last character of `value` is a valid check character.
+pub(super) fn verify(value: &str, alphabet: &str) -> bool {
+    let n = alphabet.chars().count();
+    let mut factor = 1;
+    let mut sum = 0;
+
+    for c in value.chars().rev() {
+        let Some(code_point) = code_point(alphabet, c) else {
+            return false;
+        };
+        let addend = factor * code_point;
+        factor = if factor == 2 { 1 } else { 2 };
+        sum += addend / n + addend % n;
+    }
+
+    sum % n == 0
+}
+
+/// Returns the position of `c` in `alphabet` in characters.
+fn code_point(alphabet: &str, c: char) -> Option<usize> {
+    alphabet.chars().position(|a| a == c)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "0123456789abcdef";
+
+    #[test]
+    fn test_generate_and_verify() {
+        let check = generate("1ee202ed", HEX).unwrap();
+
+        assert!(verify(&format!("1ee202ed{check}"), HEX));
+    }
+
+    #[test]
+    fn test_detects_substitution() {
+        let check = generate("1ee202ed", HEX).unwrap();
+
+        for typo in ["0ee202ed", "1fe202ed", "1ee202ee", "1ee2o2ed"] {
+            assert!(!verify(&format!("{typo}{check}"), HEX), "{typo}");
+        }
+    }
+
+    #[test]
+    fn test_detects_transposition() {
+        let check = generate("1ee202ed", HEX).unwrap();
+
+        assert!(!verify(&format!("e1e202ed{check}"), HEX));
+    }
+
+    #[test]
+    fn test_non_ascii_alphabet() {
+        const GREEK: &str = "αβγδεζηθικλμνξοπ";
+        let to_greek = |value: &str| -> String {
+            value
+                .chars()
+                .map(|c| GREEK.chars().nth(HEX.find(c).unwrap()).unwrap())
+                .collect()
+        };
+
+        // Same positions in the alphabet give the same check character
+        let value = to_greek("1ee202ed");
+        let check = generate(&value, GREEK).unwrap();
+        assert_eq!(
+            check.to_string(),
+            to_greek(&generate("1ee202ed", HEX).unwrap().to_string())
+        );
+
+        assert!(verify(&format!("{value}{check}"), GREEK));
+        assert!(!verify(&format!("{}{check}", to_greek("1ee202ee")), GREEK));
+    }
+}
+
+// endregion: --- Tests
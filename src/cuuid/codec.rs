@@ -0,0 +1,397 @@
+use uuid::Uuid;
+
+use super::{checksum, deterministic, CUuid, EncodedId, Error, Obfuscator, Result};
+
+/// `CUuid` scheme with additional encoding options.
+///
+/// Created with `CUuid::codec`, options are enabled with builder methods.
+#[derive(Debug, Clone, Copy)]
+pub struct Codec {
+    cuuid: CUuid,
+    checksum: bool,
+    strict: bool,
+    fixed_width: bool,
+    obfuscator: Option<Obfuscator>,
+}
+
+impl CUuid {
+    /// Returns a codec for this scheme with all options disabled.
+    #[inline]
+    pub fn codec(&self) -> Codec {
+        Codec {
+            cuuid: *self,
+            checksum: false,
+            strict: false,
+            fixed_width: false,
+            obfuscator: None,
+        }
+    }
+}
+
+impl Codec {
+    /// Appends a check character to every encoded id.
+    ///
+    /// The check character is a Luhn mod N over the alphabet of the scheme,
+    /// so single character typos and most swaps of adjacent characters
+    /// are rejected by `try_decode` with `Error::ChecksumMismatch`.
+    ///
+    /// # Returns
+    /// * `Ok(Codec)` with the check character enabled.
+    /// * `Err(Error::InvalidChar)` if a custom scheme encodes ids with
+    ///   characters outside of its alphabet, such ids could never be decoded.
+    pub fn with_checksum(mut self) -> Result<Codec> {
+        let scheme = self.cuuid.scheme();
+        let alphabet = scheme.alphabet();
+
+        // Every byte value in every position, checked once instead of on each encode
+        let samples = (0..=u8::MAX)
+            .map(|byte| [byte; 16])
+            .chain([core::array::from_fn(|i| i as u8)]);
+        for sample in samples {
+            let encoded = scheme.encode_id(&sample);
+            if let Some((position, char)) = encoded
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !alphabet.contains(*c))
+            {
+                return Err(Error::InvalidChar {
+                    scheme: scheme.name(),
+                    char,
+                    position,
+                });
+            }
+        }
+
+        self.checksum = true;
+        Ok(self)
+    }
+
+    /// Accepts only the canonical encoding of an id in `try_decode`.
+    ///
+    /// Decoded ids are encoded back and compared with the input, so every
+    /// id has exactly one accepted string, e.g. when used as a cache key.
+    /// Other inputs are rejected with `Error::NonCanonical`.
+    #[inline]
+    pub fn strict(mut self) -> Codec {
+        self.strict = true;
+        self
+    }
+
+    /// Pads every encoded id to `CUuid::encoded_len` characters.
+    ///
+    /// Ids are left-padded with the zero digit of the alphabet, e.g. `1`
+    /// for `B58`, so decoding accepts both padded and unpadded values.
+    #[inline]
+    pub fn fixed_width(mut self) -> Codec {
+        self.fixed_width = true;
+        self
+    }
+
+    /// Obfuscates every id before encoding and reveals it after decoding.
+    ///
+    /// Public ids become unguessable and stop leaking creation time of
+    /// UUIDv7, while the decoded ids stay the same as the stored ones.
+    #[inline]
+    pub fn with_obfuscation(mut self, obfuscator: Obfuscator) -> Codec {
+        self.obfuscator = Some(obfuscator);
+        self
+    }
+
+    /// Returns the underlying scheme.
+    #[inline]
+    pub fn cuuid(&self) -> CUuid {
+        self.cuuid
+    }
+
+    /// Converts a given id into an encoded UUID.
+    #[inline]
+    pub fn from(&self, id: impl Into<Uuid>) -> EncodedId {
+        self.encode(id.into())
+    }
+
+    /// Try to convert a given id into an encoded UUID.
+    ///
+    /// # Returns
+    /// * `Ok(EncodedId)` if the conversion is successful.
+    /// * `Err(Error::InvalidFormat)` if the conversion fails.
+    #[inline]
+    pub fn try_from(&self, id: impl TryInto<Uuid>) -> Result<EncodedId> {
+        let id = id.try_into().map_err(|_| Error::InvalidFormat)?;
+        Ok(self.encode(id))
+    }
+
+    /// Generates a new time-based UUID and encodes it.
+    #[inline]
+    pub fn now_v7(&self) -> EncodedId {
+        self.encode(deterministic::now_v7())
+    }
+
+    /// Generates a new random UUID and encodes it.
+    #[inline]
+    pub fn new_v4(&self) -> EncodedId {
+        self.encode(deterministic::new_v4())
+    }
+
+    /// Attempts to decode an encoded string into a UUID.
+    ///
+    /// # Returns
+    /// * `Ok(uuid::Uuid)` if the decoding is successful.
+    /// * `Err(Error::ChecksumMismatch)` if the check character does not match.
+    /// * `Err(Error::NonCanonical)` in strict mode if the value is not the canonical encoding.
+    /// * `Err(_)` the same errors as `CUuid::try_decode`.
+    pub fn try_decode(&self, value: &str) -> Result<Uuid> {
+        let encoded = if self.checksum {
+            self.strip_checksum(value)?
+        } else {
+            value
+        };
+
+        let id = self.cuuid.try_decode(encoded)?;
+        let id = match &self.obfuscator {
+            Some(obfuscator) => obfuscator.reveal(id),
+            None => id,
+        };
+
+        if self.strict && self.encode(id) != value {
+            return Err(Error::NonCanonical);
+        }
+
+        Ok(id)
+    }
+
+    /// Decode an encoded string into a UUID.
+    ///
+    /// # Panics
+    /// This will panic if decoding fails.
+    #[inline]
+    pub fn decode(&self, value: &str) -> Uuid {
+        self.try_decode(value).expect("Decode failed")
+    }
+
+    fn encode(&self, id: Uuid) -> EncodedId {
+        let id = match &self.obfuscator {
+            Some(obfuscator) => obfuscator.obfuscate(id),
+            None => id,
+        };
+
+        let mut encoded = self.cuuid.encode(id);
+
+        if self.fixed_width {
+            if let Some(zero) = self.cuuid.scheme().alphabet().chars().next() {
+                encoded.pad_start(zero, self.cuuid.encoded_len());
+            }
+        }
+
+        if self.checksum {
+            // The alphabet is checked in `with_checksum`, an id the check fails
+            // to cover is left without it and rejected by `try_decode`
+            if let Some(check) = checksum::generate(&encoded, self.cuuid.scheme().alphabet()) {
+                encoded.push(check);
+            }
+        }
+
+        encoded
+    }
+
+    fn strip_checksum<'a>(&self, value: &'a str) -> Result<&'a str> {
+        let Some((last, _)) = value.char_indices().last() else {
+            return Err(Error::InvalidFormat);
+        };
+
+        if !checksum::verify(value, self.cuuid.scheme().alphabet()) {
+            return Err(Error::ChecksumMismatch);
+        }
+
+        Ok(&value[..last])
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_v7() -> Uuid {
+        Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap()
+    }
+
+    /// Replaces the character at `index` with a different one from the alphabet.
+    fn typo(value: &str, index: usize, alphabet: &str) -> String {
+        value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match i == index {
+                true => alphabet.chars().find(|a| *a != c).unwrap(),
+                false => c,
+            })
+            .collect()
+    }
+
+    fn check_scheme(cuuid: CUuid) -> Result<()> {
+        let codec = cuuid.codec().with_checksum()?;
+        let fx_id = get_v7();
+
+        let encoded = codec.from(fx_id);
+        assert_eq!(encoded.len(), cuuid.from(fx_id).len() + 1);
+        assert_eq!(codec.try_decode(&encoded)?, fx_id);
+
+        let alphabet = cuuid.scheme().alphabet();
+        for index in 0..encoded.len() {
+            let result = codec.try_decode(&typo(&encoded, index, alphabet));
+            assert!(matches!(result, Err(Error::ChecksumMismatch)), "{index}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_checksum_b58() -> Result<()> {
+        check_scheme(CUuid::B58)
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_checksum_b64() -> Result<()> {
+        check_scheme(CUuid::B64)
+    }
+
+    #[test]
+    #[cfg(feature = "b32")]
+    fn test_checksum_b32() -> Result<()> {
+        check_scheme(CUuid::B32)
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_obfuscation() -> Result<()> {
+        let obfuscator = Obfuscator::new(b"0123456789abcdef")?;
+        let codec = CUuid::B64.codec().with_obfuscation(obfuscator);
+        let fx_id = get_v7();
+
+        let encoded = codec.from(fx_id);
+        assert_ne!(encoded, CUuid::B64.from(fx_id));
+        assert_eq!(codec.try_decode(&encoded)?, fx_id);
+
+        let checked = codec.with_checksum()?;
+        assert_eq!(checked.try_decode(&checked.from(fx_id))?, fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict() -> Result<()> {
+        let codec = CUuid::Custom(&crate::cuuid::tests::SchemeHex).codec();
+        let fx_id = get_v7();
+
+        // Hex decoding accepts both cases, only lowercase is canonical
+        let uppercase = "019703702E2D76E38C693BB5E943B2A2";
+        assert_eq!(codec.try_decode(uppercase)?, fx_id);
+
+        let strict = codec.strict();
+        assert!(matches!(
+            strict.try_decode(uppercase),
+            Err(Error::NonCanonical)
+        ));
+        assert_eq!(strict.try_decode(&strict.from(fx_id))?, fx_id);
+
+        let checked = strict.with_checksum()?;
+        assert_eq!(checked.try_decode(&checked.from(fx_id))?, fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_strict_trailing_bits() {
+        let strict = CUuid::B64.codec().strict();
+
+        assert!(strict.try_decode("AZcDcC4tduOMaTu16UOyog").is_ok());
+        assert!(strict.try_decode("AZcDcC4tduOMaTu16UOyoh").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_fixed_width() -> Result<()> {
+        let codec = CUuid::B58.codec().fixed_width();
+        let fx_id = get_v7();
+
+        // Unpadded value is one character shorter
+        assert_eq!(CUuid::B58.from(fx_id), "CPSnYpT5QSYA2h1RwKSc1");
+        assert_eq!(codec.from(fx_id), "1CPSnYpT5QSYA2h1RwKSc1");
+        assert_eq!(codec.from(Uuid::nil()).len(), CUuid::B58.encoded_len());
+
+        assert_eq!(codec.try_decode("1CPSnYpT5QSYA2h1RwKSc1")?, fx_id);
+        assert_eq!(codec.try_decode("CPSnYpT5QSYA2h1RwKSc1")?, fx_id);
+        assert_eq!(CUuid::B58.try_decode("1CPSnYpT5QSYA2h1RwKSc1")?, fx_id);
+
+        let strict = codec.strict().with_checksum()?;
+        assert_eq!(strict.from(fx_id).len(), CUuid::B58.encoded_len() + 1);
+        assert_eq!(strict.try_decode(&strict.from(fx_id))?, fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoded_len() {
+        for cuuid in CUuid::available() {
+            let codec = cuuid.codec().fixed_width();
+            for id in [Uuid::nil(), Uuid::max(), get_v7()] {
+                assert_eq!(codec.from(id).len(), cuuid.encoded_len(), "{cuuid}");
+                assert!(cuuid.from(id).len() <= cuuid.encoded_len(), "{cuuid}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_without_checksum() -> Result<()> {
+        let codec = CUuid::B58.codec();
+        let fx_id = get_v7();
+
+        assert_eq!(codec.decode(&codec.from(fx_id)), fx_id);
+        assert!(codec.try_decode("").is_err());
+        assert!(codec.with_checksum()?.try_decode("").is_err());
+
+        Ok(())
+    }
+
+    /// Hex scheme that claims only digits, but encodes `a..f` as well.
+    #[derive(Debug)]
+    struct SchemeDigits;
+
+    impl crate::cuuid::Scheme for SchemeDigits {
+        fn name(&self) -> &'static str {
+            "digits"
+        }
+
+        fn encode(&self, content: &[u8]) -> String {
+            crate::cuuid::tests::SchemeHex.encode(content)
+        }
+
+        fn try_decode(&self, content: &str) -> crate::cuuid::scheme::Result<Vec<u8>> {
+            crate::cuuid::tests::SchemeHex.try_decode(content)
+        }
+
+        fn alphabet(&self) -> &'static str {
+            "0123456789"
+        }
+    }
+
+    #[test]
+    fn test_checksum_outside_alphabet() {
+        let result = CUuid::Custom(&SchemeDigits).codec().with_checksum();
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidChar {
+                scheme: "digits",
+                char: 'a',
+                ..
+            })
+        ));
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,333 @@
+#[cfg(feature = "test-util")]
+use std::cell::RefCell;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use uuid::{Builder, Uuid, Version};
+
+use super::{CUuid, EncodedId};
+
+/// Default start of the fake clock, 2023-11-14T22:13:20Z.
+const DEFAULT_START_MS: u64 = 1_700_000_000_000;
+
+#[cfg(feature = "test-util")]
+thread_local! {
+    static INSTALLED: RefCell<Option<Generator>> = const { RefCell::new(None) };
+}
+
+/// Clock returning a predictable sequence of millisecond timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FakeClock {
+    now_ms: u64,
+    step_ms: u64,
+}
+
+impl FakeClock {
+    /// Creates a clock starting at `start_ms` and advancing by 1 ms on every read.
+    #[inline]
+    pub fn new(start_ms: u64) -> FakeClock {
+        FakeClock {
+            now_ms: start_ms,
+            step_ms: 1,
+        }
+    }
+
+    /// Sets the step of the clock, `0` freezes it.
+    #[inline]
+    pub fn with_step(mut self, step_ms: u64) -> FakeClock {
+        self.step_ms = step_ms;
+        self
+    }
+
+    /// Returns the current timestamp without advancing the clock.
+    #[inline]
+    pub fn now_ms(&self) -> u64 {
+        self.now_ms
+    }
+
+    /// Moves the clock forward by `ms`.
+    #[inline]
+    pub fn advance(&mut self, ms: u64) {
+        self.now_ms = self.now_ms.saturating_add(ms);
+    }
+
+    /// Returns the current timestamp and advances the clock by its step.
+    #[inline]
+    pub fn tick(&mut self) -> u64 {
+        let now_ms = self.now_ms;
+        self.advance(self.step_ms);
+        now_ms
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        FakeClock::new(DEFAULT_START_MS)
+    }
+}
+
+/// Seedable generator of reproducible UUIDs.
+///
+/// The same seed and clock always produce the same sequence of ids.
+/// Use it explicitly, e.g. with `CUuid::new_v4_with`, or with the `test-util`
+/// feature `install` it for the current thread, so `CUuid::new_v4`,
+/// `CUuid::now_v7` and ULID generation use it instead of the system
+/// randomness and time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generator {
+    state: u64,
+    clock: FakeClock,
+}
+
+impl Generator {
+    /// Creates a generator from a seed with the default fake clock.
+    #[inline]
+    pub fn new(seed: u64) -> Generator {
+        Generator {
+            state: seed,
+            clock: FakeClock::default(),
+        }
+    }
+
+    /// Replaces the fake clock of the generator.
+    #[inline]
+    pub fn with_clock(mut self, clock: FakeClock) -> Generator {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the fake clock of the generator.
+    #[inline]
+    pub fn clock(&mut self) -> &mut FakeClock {
+        &mut self.clock
+    }
+
+    /// Returns the next pseudo-random number.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a reproducible random UUID.
+    pub fn new_v4(&mut self) -> Uuid {
+        Builder::from_random_bytes(self.next_bytes()).into_uuid()
+    }
+
+    /// Generates a reproducible time-based UUID using the fake clock.
+    pub fn now_v7(&mut self) -> Uuid {
+        let timestamp_ms = self.clock.tick();
+        let mut random = [0u8; 10];
+        random.copy_from_slice(&self.next_bytes()[..10]);
+        Builder::from_unix_timestamp_millis(timestamp_ms, &random).into_uuid()
+    }
+
+    fn next_bytes(&mut self) -> [u8; 16] {
+        let value = (self.next_u64() as u128) << 64 | self.next_u64() as u128;
+        value.to_be_bytes()
+    }
+}
+
+/// Restores the previously installed generator when dropped.
+#[cfg(feature = "test-util")]
+#[derive(Debug)]
+#[must_use = "the generator is uninstalled when the guard is dropped"]
+pub struct Guard {
+    previous: Option<Generator>,
+}
+
+#[cfg(feature = "test-util")]
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        INSTALLED.with(|installed| *installed.borrow_mut() = previous);
+    }
+}
+
+/// Installs the generator for the current thread until the guard is dropped.
+#[cfg(feature = "test-util")]
+pub fn install(generator: Generator) -> Guard {
+    let previous = INSTALLED.with(|installed| installed.borrow_mut().replace(generator));
+    Guard { previous }
+}
+
+/// Returns `true` if a generator is installed for the current thread.
+#[cfg(feature = "test-util")]
+#[inline]
+pub fn is_installed() -> bool {
+    INSTALLED.with(|installed| installed.borrow().is_some())
+}
+
+impl CUuid {
+    /// Generates a random UUID with the given generator and encodes it.
+    #[inline]
+    pub fn new_v4_with(&self, generator: &mut Generator) -> EncodedId {
+        self.encode(generator.new_v4())
+    }
+
+    /// Generates a time-based UUID with the given generator and encodes it.
+    #[inline]
+    pub fn now_v7_with(&self, generator: &mut Generator) -> EncodedId {
+        self.encode(generator.now_v7())
+    }
+}
+
+// region:    --- Assertions
+
+/// Asserts that the value is an id encoded with the scheme and has the version.
+///
+/// # Panics
+/// This will panic if the value does not decode or has another version.
+#[track_caller]
+pub fn assert_version(cuuid: CUuid, value: &str, version: Version) {
+    match cuuid.try_version(value) {
+        Ok(Some(actual)) if actual == version => {}
+        Ok(actual) => panic!("Id `{value}` has version {actual:?}, expected {version:?}"),
+        Err(err) => panic!("Id `{value}` is not a valid `{cuuid}` id: {err}"),
+    }
+}
+
+/// Asserts that the value is the canonical encoding of an id with the scheme.
+///
+/// # Panics
+/// This will panic if the value does not decode strictly.
+#[track_caller]
+pub fn assert_scheme(cuuid: CUuid, value: &str) {
+    if let Err(err) = cuuid.try_decode_strict(value) {
+        panic!("Id `{value}` is not a valid `{cuuid}` id: {err}");
+    }
+}
+
+// endregion: --- Assertions
+
+/// Random UUID from the installed generator or the system.
+#[inline]
+pub(super) fn new_v4() -> Uuid {
+    #[cfg(feature = "test-util")]
+    if let Some(id) = with_installed(Generator::new_v4) {
+        return id;
+    }
+
+    Uuid::new_v4()
+}
+
+/// Time-based UUID from the installed generator or the system.
+#[inline]
+pub(super) fn now_v7() -> Uuid {
+    #[cfg(feature = "test-util")]
+    if let Some(id) = with_installed(Generator::now_v7) {
+        return id;
+    }
+
+    Uuid::now_v7()
+}
+
+/// Current timestamp from the installed fake clock or the system.
+pub(super) fn now_ms() -> u64 {
+    #[cfg(feature = "test-util")]
+    if let Some(now_ms) = with_installed(|generator| generator.clock.tick()) {
+        return now_ms;
+    }
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "test-util")]
+fn with_installed<T>(f: impl FnOnce(&mut Generator) -> T) -> Option<T> {
+    INSTALLED.with(|installed| installed.borrow_mut().as_mut().map(f))
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cuuid::tests::SchemeHex;
+    #[cfg(feature = "test-util")]
+    use crate::cuuid::Ulid;
+
+    const C: CUuid = CUuid::Custom(&SchemeHex);
+
+    #[test]
+    fn test_same_seed() {
+        let mut first = Generator::new(7);
+        let mut second = Generator::new(7);
+
+        assert_eq!(first.new_v4(), second.new_v4());
+        assert_eq!(first.now_v7(), second.now_v7());
+        assert_ne!(first.new_v4(), Generator::new(8).new_v4());
+    }
+
+    #[test]
+    fn test_versions() {
+        let mut generator = Generator::new(1);
+
+        let v4 = C.new_v4_with(&mut generator);
+        let v7 = C.now_v7_with(&mut generator);
+
+        assert_version(C, &v4, Version::Random);
+        assert_version(C, &v7, Version::SortRand);
+        assert_scheme(C, &v7);
+    }
+
+    #[test]
+    fn test_fake_clock() {
+        let clock = FakeClock::new(1_000).with_step(10);
+        let mut generator = Generator::new(1).with_clock(clock);
+
+        let first = generator.now_v7();
+        generator.clock().advance(1_000);
+        let second = generator.now_v7();
+
+        assert_eq!(first.get_timestamp().map(|t| t.to_unix().0), Some(1));
+        assert_eq!(second.get_timestamp().map(|t| t.to_unix().0), Some(2));
+        assert_eq!(generator.clock().now_ms(), 2_020);
+    }
+
+    #[test]
+    #[cfg(feature = "test-util")]
+    fn test_install() {
+        let expected = C.new_v4_with(&mut Generator::new(3));
+
+        {
+            let _guard = install(Generator::new(3));
+            assert!(is_installed());
+            assert_eq!(C.new_v4(), expected);
+
+            let ulid = Ulid::new();
+            assert_eq!(ulid.timestamp_ms(), DEFAULT_START_MS);
+        }
+
+        assert!(!is_installed());
+        assert_ne!(C.new_v4(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "test-util")]
+    fn test_install_nested() {
+        let _outer = install(Generator::new(1));
+        let first = C.codec().new_v4();
+
+        {
+            let _inner = install(Generator::new(1));
+            assert_eq!(C.codec().new_v4(), first);
+        }
+
+        // The outer generator continues its sequence
+        assert_ne!(C.codec().new_v4(), first);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected Random")]
+    fn test_assert_version_fails() {
+        let id = C.now_v7_with(&mut Generator::new(1));
+        assert_version(C, &id, Version::Random);
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,303 @@
+use uuid::Uuid;
+
+use super::scheme::SchemeCrockford;
+use super::{check_len, decode_error, CUuid, Error, Result};
+
+/// Groups of characters commonly mistaken for each other.
+const CONFUSABLE: &[&[char]] = &[&['0', 'O', 'o'], &['1', 'I', 'i', 'L', 'l']];
+
+/// Formatter of human-friendly codes like `7K3F-9QXA-M2PD`.
+///
+/// Encodes the whole UUID or only its lowest bits, splits the result into
+/// groups and parses it back ignoring separators, whitespace and case for
+/// single-case alphabets.
+///
+/// `DisplayCode::crockford` gives the best results, as Crockford's Base32 has
+/// a single case and reads mistyped `O` as `0`, `I` and `L` as `1`. Other
+/// schemes replace such characters only if they are not digits of the
+/// alphabet, e.g. `O` and `0` are different digits of `CUuid::B32`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayCode {
+    cuuid: CUuid,
+    group_size: usize,
+    separator: char,
+    bits: u32,
+}
+
+impl DisplayCode {
+    /// Creates a formatter of full UUIDs in groups of 4 characters.
+    ///
+    /// The separator is `-`, or `.` if `-` belongs to the scheme alphabet.
+    pub fn new(cuuid: CUuid) -> DisplayCode {
+        let separator = match cuuid.scheme().alphabet().contains('-') {
+            true => '.',
+            false => '-',
+        };
+
+        DisplayCode {
+            cuuid,
+            group_size: 4,
+            separator,
+            bits: 128,
+        }
+    }
+
+    /// Creates a formatter of full UUIDs in Crockford's Base32, in groups of 4 characters.
+    #[inline]
+    pub fn crockford() -> DisplayCode {
+        DisplayCode::new(CUuid::Custom(&SchemeCrockford))
+    }
+
+    /// Sets the number of characters in a group, `0` disables grouping.
+    #[inline]
+    pub fn group_size(mut self, group_size: usize) -> DisplayCode {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets the separator between groups.
+    ///
+    /// # Panics
+    /// This will panic if the separator belongs to the scheme alphabet,
+    /// codes with it could not be parsed back.
+    #[inline]
+    pub fn separator(mut self, separator: char) -> DisplayCode {
+        assert!(
+            !self.cuuid.scheme().alphabet().contains(separator),
+            "Separator `{separator}` belongs to the alphabet of `{}`",
+            self.cuuid
+        );
+        self.separator = separator;
+        self
+    }
+
+    /// Keeps only the lowest `bits` of the UUID, for shorter codes.
+    ///
+    /// The value is clamped into `1..=128`.
+    #[inline]
+    pub fn bits(mut self, bits: u32) -> DisplayCode {
+        self.bits = bits.clamp(1, 128);
+        self
+    }
+
+    /// Formats the id into a grouped code.
+    pub fn format(&self, id: impl Into<Uuid>) -> String {
+        let value = self.truncate(id.into()).as_u128().to_be_bytes();
+        let encoded = self.cuuid.scheme().encode(&value[16 - self.byte_len()..]);
+
+        if self.group_size == 0 {
+            return encoded;
+        }
+
+        let mut code = String::with_capacity(encoded.len() + encoded.len() / self.group_size);
+        for (i, c) in encoded.chars().enumerate() {
+            if i > 0 && i % self.group_size == 0 {
+                code.push(self.separator);
+            }
+            code.push(c);
+        }
+
+        code
+    }
+
+    /// Attempts to parse a code back into an id.
+    ///
+    /// With truncation only the lowest `bits` of the returned id are set,
+    /// compare it with `truncate` of the original id.
+    ///
+    /// # Returns
+    /// * `Ok(uuid::Uuid)` if the parsing is successful.
+    /// * `Err(Error::InvalidChar)` or `Err(Error::FailToDecode)` if the code is not valid in the scheme.
+    /// * `Err(Error::InvalidLength)` or `Err(Error::TooLong)` if the code does not fit into the configured bits.
+    pub fn try_parse(&self, code: &str) -> Result<Uuid> {
+        let normalized = self.normalize(code);
+        let scheme = self.cuuid.scheme();
+
+        // Longer codes never fit, and some schemes fail on very long input
+        let max_len = scheme
+            .encode(&[u8::MAX; 16][..self.byte_len()])
+            .chars()
+            .count();
+        check_len(scheme, &normalized, max_len)?;
+
+        let bytes = scheme
+            .try_decode(&normalized)
+            .map_err(|err| decode_error(scheme, &normalized, err))?;
+
+        let invalid_length = Error::InvalidLength {
+            scheme: scheme.name(),
+            len: bytes.len(),
+        };
+        if normalized.is_empty() || bytes.len() != self.byte_len() {
+            return Err(invalid_length);
+        }
+
+        let mut value = [0u8; 16];
+        value[16 - bytes.len()..].copy_from_slice(&bytes);
+        let id = Uuid::from_bytes(value);
+
+        match self.truncate(id) == id {
+            true => Ok(id),
+            false => Err(invalid_length),
+        }
+    }
+
+    /// Keeps only the configured lowest bits of the id.
+    pub fn truncate(&self, id: Uuid) -> Uuid {
+        let mask = u128::MAX >> (128 - self.bits);
+        Uuid::from_u128(id.as_u128() & mask)
+    }
+
+    #[inline]
+    fn byte_len(&self) -> usize {
+        self.bits.div_ceil(8) as usize
+    }
+
+    /// Strips separators and whitespace, fixes case and confusable characters.
+    fn normalize(&self, code: &str) -> String {
+        let alphabet = self.cuuid.scheme().alphabet();
+        let has_lower = alphabet.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = alphabet.chars().any(|c| c.is_ascii_uppercase());
+
+        code.chars()
+            .filter(|c| *c != self.separator && !c.is_whitespace())
+            .map(|c| match (has_lower, has_upper) {
+                (false, true) => c.to_ascii_uppercase(),
+                (true, false) => c.to_ascii_lowercase(),
+                _ => c,
+            })
+            .map(|c| {
+                if alphabet.contains(c) {
+                    return c;
+                }
+
+                // Replace only if a single character of the group is valid
+                let group = CONFUSABLE.iter().find(|group| group.contains(&c));
+                let mut valid = group
+                    .into_iter()
+                    .flat_map(|group| group.iter())
+                    .filter(|g| alphabet.contains(**g));
+
+                match (valid.next(), valid.next()) {
+                    (Some(g), None) => *g,
+                    _ => c,
+                }
+            })
+            .collect()
+    }
+}
+
+impl CUuid {
+    /// Returns a formatter of human-friendly grouped codes in this scheme.
+    #[inline]
+    pub fn display_code(&self) -> DisplayCode {
+        DisplayCode::new(*self)
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "b32")]
+    fn get_v4() -> Uuid {
+        Uuid::parse_str("1ee202ed-9090-4331-af06-c2617155f04a").unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "b32")]
+    fn test_format_b32() -> Result<()> {
+        let code = CUuid::B32.display_code();
+
+        let formatted = code.format(get_v4());
+
+        assert_eq!(formatted, "3RH0-5RCG-I11J-3BO6-O9GN-2LFG-98");
+        assert_eq!(code.try_parse(&formatted)?, get_v4());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_crockford() -> Result<()> {
+        let code = DisplayCode::crockford().bits(60);
+        let fx_id = Uuid::parse_str("1ee202ed-9090-4331-af06-c2617155f04a").unwrap();
+
+        let formatted = code.format(fx_id);
+        assert_eq!(formatted, "0Y1P-2C5R-NBW2-A");
+
+        // Mistyped `0` and `1` are read as the same digits
+        let parsed = code.try_parse("oyip 2c5r nbw2 a")?;
+        assert_eq!(parsed, code.truncate(fx_id));
+        assert_eq!(code.try_parse("0YLP-2C5R-NBW2-A")?, parsed);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b32")]
+    fn test_parse_normalized() -> Result<()> {
+        let code = CUuid::B32.display_code().bits(60).separator(' ');
+
+        let formatted = code.format(get_v4());
+        assert_eq!(formatted, "1S3C 4OBH ANO4 K");
+
+        // Case and whitespace are ignored
+        let parsed = code.try_parse(" 1s3c4obh\tano4k ")?;
+        assert_eq!(parsed, code.truncate(get_v4()));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_parse_confusable_b58() -> Result<()> {
+        let code = CUuid::B58.display_code().group_size(5);
+        let fx_id = Uuid::parse_str("d82e147e-f3f1-d65b-c92f-50aa19599206").unwrap();
+
+        let formatted = code.format(fx_id);
+        assert_eq!(formatted, "ThJQH-uMhuC-vnprX-SvSno-81");
+
+        // `0` is not in the alphabet, only `o` is
+        assert_eq!(code.try_parse("ThJQH-uMhuC-vnprX-SvSn0-81")?, fx_id);
+        assert!(matches!(
+            code.try_parse(&"1".repeat(200)),
+            Err(Error::TooLong { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_separator_not_in_alphabet() -> Result<()> {
+        let code = CUuid::B64.display_code();
+        let fx_id = Uuid::from_u128(u128::MAX);
+
+        let formatted = code.format(fx_id);
+        assert_eq!(formatted, "____.____.____.____.____._w");
+        assert_eq!(code.try_parse(&formatted)?, fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Separator `A` belongs to the alphabet")]
+    fn test_separator_in_alphabet() {
+        let _ = DisplayCode::crockford().separator('A');
+    }
+
+    #[test]
+    #[cfg(feature = "b32")]
+    fn test_parse_invalid() {
+        let code = CUuid::B32.display_code().bits(20);
+
+        assert!(code.try_parse("").is_err());
+        assert!(code.try_parse("W").is_err());
+        // Fits into 3 bytes, but not into 20 bits
+        assert!(code.try_parse("VVVVV").is_err());
+    }
+}
+
+// endregion: --- Tests
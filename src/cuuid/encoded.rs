@@ -0,0 +1,285 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// Capacity of the inline buffer, fits every built-in scheme with a check character.
+const INLINE_CAP: usize = 32;
+
+/// Encoded UUID stored on the stack.
+///
+/// Built-in schemes encode 16 bytes into at most 27 characters including
+/// the check character, so no heap allocation is needed. Longer values
+/// produced by custom schemes are stored on the heap.
+#[derive(Clone)]
+pub struct EncodedId(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAP] },
+    Heap(String),
+}
+
+impl EncodedId {
+    /// Creates an id from an ASCII buffer written by `write`, which returns the written length.
+    ///
+    /// # Panics
+    /// This will panic if written bytes are not valid UTF-8.
+    pub(crate) fn from_fn(write: impl FnOnce(&mut [u8; INLINE_CAP]) -> usize) -> EncodedId {
+        let mut buf = [0u8; INLINE_CAP];
+        let len = write(&mut buf);
+
+        assert!(
+            core::str::from_utf8(&buf[..len]).is_ok(),
+            "Encoded id is not valid UTF-8"
+        );
+
+        EncodedId(Repr::Inline {
+            len: len as u8,
+            buf,
+        })
+    }
+
+    /// Returns the encoded id as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            // Only valid UTF-8 is ever stored inline
+            Repr::Inline { len, buf } => core::str::from_utf8(&buf[..*len as usize]).unwrap_or(""),
+            Repr::Heap(value) => value,
+        }
+    }
+
+    /// Returns `true` if the id is stored without heap allocation.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline { .. })
+    }
+
+    /// Converts the id into a `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        match self.0 {
+            Repr::Inline { .. } => self.as_str().to_string(),
+            Repr::Heap(value) => value,
+        }
+    }
+
+    /// Appends a character, moves the id to the heap if it does not fit anymore.
+    pub(crate) fn push(&mut self, c: char) {
+        match &mut self.0 {
+            Repr::Inline { len, buf } if (*len as usize) + c.len_utf8() <= INLINE_CAP => {
+                let start = *len as usize;
+                c.encode_utf8(&mut buf[start..]);
+                *len += c.len_utf8() as u8;
+            }
+            Repr::Inline { .. } => {
+                let mut value = self.as_str().to_string();
+                value.push(c);
+                self.0 = Repr::Heap(value);
+            }
+            Repr::Heap(value) => value.push(c),
+        }
+    }
+
+    /// Prepends `fill` until the id has `len` characters.
+    pub(crate) fn pad_start(&mut self, fill: char, len: usize) {
+        let count = len.saturating_sub(self.chars().count());
+        if count == 0 {
+            return;
+        }
+
+        let mut padded = EncodedId::from("");
+        for c in core::iter::repeat_n(fill, count).chain(self.chars()) {
+            padded.push(c);
+        }
+
+        *self = padded;
+    }
+}
+
+impl From<&str> for EncodedId {
+    fn from(value: &str) -> Self {
+        if value.len() > INLINE_CAP {
+            return EncodedId(Repr::Heap(value.to_string()));
+        }
+
+        EncodedId::from_fn(|buf| {
+            buf[..value.len()].copy_from_slice(value.as_bytes());
+            value.len()
+        })
+    }
+}
+
+impl From<String> for EncodedId {
+    fn from(value: String) -> Self {
+        if value.len() > INLINE_CAP {
+            return EncodedId(Repr::Heap(value));
+        }
+
+        EncodedId::from(value.as_str())
+    }
+}
+
+impl From<EncodedId> for String {
+    #[inline]
+    fn from(id: EncodedId) -> Self {
+        id.into_string()
+    }
+}
+
+impl Deref for EncodedId {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for EncodedId {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for EncodedId {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Display for EncodedId {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        fmt.pad(self.as_str())
+    }
+}
+
+impl core::fmt::Debug for EncodedId {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        core::fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+// region:    --- Comparison
+
+impl PartialEq for EncodedId {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for EncodedId {}
+
+impl PartialEq<str> for EncodedId {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for EncodedId {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for EncodedId {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialOrd for EncodedId {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EncodedId {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for EncodedId {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Same hash as `str`, so lookups with `Borrow<str>` work
+        self.as_str().hash(state)
+    }
+}
+
+// endregion: --- Comparison
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_inline() {
+        let id = EncodedId::from("AZcDcC4tduOMaTu16UOyog");
+
+        assert!(id.is_inline());
+        assert_eq!(id, "AZcDcC4tduOMaTu16UOyog");
+        assert_eq!(id.len(), 22);
+        assert_eq!(id.to_string(), "AZcDcC4tduOMaTu16UOyog");
+        assert_eq!(String::from(id), "AZcDcC4tduOMaTu16UOyog");
+    }
+
+    #[test]
+    fn test_heap() {
+        let value = "x".repeat(INLINE_CAP + 1);
+        let id = EncodedId::from(value.clone());
+
+        assert!(!id.is_inline());
+        assert_eq!(id, value);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut id = EncodedId::from("x".repeat(INLINE_CAP - 1));
+
+        id.push('a');
+        assert!(id.is_inline());
+        assert_eq!(id.len(), INLINE_CAP);
+
+        id.push('b');
+        assert!(!id.is_inline());
+        assert!(id.ends_with("ab"));
+    }
+
+    #[test]
+    fn test_pad_start() {
+        let mut id = EncodedId::from("abc");
+
+        id.pad_start('1', 5);
+        assert_eq!(id, "11abc");
+
+        id.pad_start('1', 3);
+        assert_eq!(id, "11abc");
+    }
+
+    #[test]
+    fn test_hash_and_ord() {
+        let set: HashSet<EncodedId> = ["b", "a"].into_iter().map(EncodedId::from).collect();
+        assert!(set.contains("a"));
+
+        let mut sorted: Vec<EncodedId> = set.into_iter().collect();
+        sorted.sort();
+        assert_eq!(sorted, ["a", "b"]);
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,258 @@
+use uuid::Uuid;
+
+use super::{CUuid, Error, Result};
+
+/// Textual representation a UUID was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Hyphenated,
+    /// `67e5504410b1426f9247bb680e5fe0c8`
+    Simple,
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+    Braced,
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Urn,
+    /// BaseX string in one of the `CUuid` schemes.
+    Encoded(CUuid),
+}
+
+impl Format {
+    /// Detects standard textual forms by their length.
+    fn textual(value: &str) -> Option<Format> {
+        match value.len() {
+            32 => Some(Format::Simple),
+            36 => Some(Format::Hyphenated),
+            38 => Some(Format::Braced),
+            45 => Some(Format::Urn),
+            _ => None,
+        }
+    }
+}
+
+impl CUuid {
+    /// Parses a UUID from any supported textual representation.
+    ///
+    /// Accepts the standard forms (hyphenated, simple, braced and URN)
+    /// as well as every enabled BaseX scheme.
+    ///
+    /// Standard forms are detected by their length. If a value decodes in
+    /// several schemes, only the schemes it is the canonical encoding in are
+    /// kept. Every `B58` character is also in the `B64` alphabet and both
+    /// encode ids into 22 characters, so some values are still canonical in
+    /// both and decode to different ids, use `parse_any_prefer` for them.
+    ///
+    /// # Arguments
+    /// * `value` - A UUID string in any supported format.
+    ///
+    /// # Returns
+    /// * `Ok((uuid::Uuid, Format))` with the format that matched.
+    /// * `Err(Error::InvalidFormat)` if no format matches.
+    /// * `Err(Error::AmbiguousFormat)` if the value is valid in several schemes.
+    #[inline]
+    pub fn parse_any(value: &str) -> Result<(Uuid, Format)> {
+        parse_any(value, None)
+    }
+
+    /// Parses a UUID from any supported textual representation,
+    /// resolving ambiguous values in favor of the preferred scheme.
+    ///
+    /// # Arguments
+    /// * `value` - A UUID string in any supported format.
+    /// * `preferred` - The scheme used if the value is valid in several schemes.
+    ///
+    /// # Returns
+    /// The same as `parse_any`, `Err(Error::AmbiguousFormat)` only if the value
+    /// is valid in several schemes other than the preferred one.
+    #[inline]
+    pub fn parse_any_prefer(value: &str, preferred: CUuid) -> Result<(Uuid, Format)> {
+        parse_any(value, Some(preferred))
+    }
+}
+
+fn parse_any(value: &str, preferred: Option<CUuid>) -> Result<(Uuid, Format)> {
+    if value.is_empty() {
+        return Err(Error::InvalidFormat);
+    }
+
+    if let Some(format) = Format::textual(value) {
+        return Uuid::parse_str(value)
+            .map(|id| (id, format))
+            .map_err(|_| Error::InvalidFormat);
+    }
+
+    let found: Vec<(Uuid, CUuid, bool)> = CUuid::available()
+        .iter()
+        .filter_map(|cuuid| {
+            let id = cuuid.try_decode(value).ok()?;
+            let canonical =
+                cuuid.from(id) == value || cuuid.codec().fixed_width().from(id) == value;
+
+            Some((id, *cuuid, canonical))
+        })
+        .collect();
+
+    // Non-canonical matches are used only if there are no canonical ones
+    let mut candidates: Vec<(Uuid, CUuid)> = match found.iter().any(|(.., c)| *c) {
+        true => found
+            .into_iter()
+            .filter(|(.., canonical)| *canonical)
+            .map(|(id, cuuid, _)| (id, cuuid))
+            .collect(),
+        false => found
+            .into_iter()
+            .map(|(id, cuuid, _)| (id, cuuid))
+            .collect(),
+    };
+
+    if candidates.len() > 1 {
+        if let Some(preferred) = preferred {
+            candidates.retain(|(_, cuuid)| *cuuid == preferred);
+        }
+    }
+
+    match candidates.len() {
+        0 => Err(Error::InvalidFormat),
+        1 => Ok(candidates
+            .pop()
+            .map(|(id, cuuid)| (id, Format::Encoded(cuuid)))
+            .ok_or(Error::InvalidFormat)?),
+        _ => Err(Error::AmbiguousFormat(
+            candidates
+                .into_iter()
+                .map(|(_, cuuid)| Format::Encoded(cuuid))
+                .collect(),
+        )),
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "01970370-2e2d-76e3-8c69-3bb5e943b2a2";
+
+    fn get_id() -> Uuid {
+        Uuid::parse_str(ID).unwrap()
+    }
+
+    #[test]
+    fn test_parse_any_textual() -> Result<()> {
+        let fx_id = get_id();
+
+        let (id, format) = CUuid::parse_any(ID)?;
+        assert_eq!(id, fx_id);
+        assert!(matches!(format, Format::Hyphenated));
+
+        let (id, format) = CUuid::parse_any("019703702e2d76e38c693bb5e943b2a2")?;
+        assert_eq!(id, fx_id);
+        assert!(matches!(format, Format::Simple));
+
+        let (id, format) = CUuid::parse_any("{01970370-2e2d-76e3-8c69-3bb5e943b2a2}")?;
+        assert_eq!(id, fx_id);
+        assert!(matches!(format, Format::Braced));
+
+        let (id, format) = CUuid::parse_any("urn:uuid:01970370-2e2d-76e3-8c69-3bb5e943b2a2")?;
+        assert_eq!(id, fx_id);
+        assert!(matches!(format, Format::Urn));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_parse_any_b58() -> Result<()> {
+        let (id, format) = CUuid::parse_any("CPSnYpT5QSYA2h1RwKSc1")?;
+
+        assert_eq!(id, get_id());
+        assert!(matches!(format, Format::Encoded(CUuid::B58)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_parse_any_b64() -> Result<()> {
+        let (id, format) = CUuid::parse_any("AZcDcC4tduOMaTu16UOyog")?;
+
+        assert_eq!(id, get_id());
+        assert!(matches!(format, Format::Encoded(CUuid::B64)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b32")]
+    fn test_parse_any_b32() -> Result<()> {
+        let (id, format) = CUuid::parse_any("06BG6S1E5LRE73397EQUIGTIK8")?;
+
+        assert_eq!(id, get_id());
+        assert!(matches!(format, Format::Encoded(CUuid::B32)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "b58", feature = "b64"))]
+    fn test_parse_any_ambiguous() -> Result<()> {
+        let mut ambiguous = 0;
+
+        for _ in 0..2000 {
+            let fx_id = Uuid::new_v4();
+
+            for cuuid in [CUuid::B58, CUuid::B64] {
+                let encoded = cuuid.from(fx_id);
+
+                // Never a wrong id: canonical in both schemes is ambiguous
+                match CUuid::parse_any(&encoded) {
+                    Err(Error::AmbiguousFormat(formats)) => {
+                        ambiguous += 1;
+                        assert!(formats.contains(&Format::Encoded(cuuid)));
+                    }
+                    result => assert_eq!(result?, (fx_id, Format::Encoded(cuuid))),
+                }
+
+                assert_eq!(
+                    CUuid::parse_any_prefer(&encoded, cuuid)?,
+                    (fx_id, Format::Encoded(cuuid))
+                );
+            }
+        }
+        assert!(ambiguous > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "b58", feature = "b64"))]
+    fn test_parse_any_b58_canonical_b64() -> Result<()> {
+        // B58 id that is also a canonical B64 id of another UUID
+        let b58 = (0..)
+            .map(|_| CUuid::B58.from(Uuid::new_v4()))
+            .find(|encoded| CUuid::B64.try_decode_strict(encoded).is_ok())
+            .unwrap();
+        let fx_id = CUuid::B58.decode(&b58);
+        assert_ne!(CUuid::B64.decode(&b58), fx_id);
+
+        let result = CUuid::parse_any(&b58);
+        assert!(matches!(result, Err(Error::AmbiguousFormat(formats)) if formats.len() == 2));
+
+        assert_eq!(
+            CUuid::parse_any_prefer(&b58, CUuid::B58)?,
+            (fx_id, Format::Encoded(CUuid::B58))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_any_invalid() {
+        assert!(CUuid::parse_any("").is_err());
+        assert!(CUuid::parse_any("not an id").is_err());
+        assert!(CUuid::parse_any("01970370-2e2d-76e3-8c69-3bb5e943b2zz").is_err());
+    }
+}
+
+// endregion: --- Tests
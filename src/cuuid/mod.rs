@@ -1,13 +1,66 @@
-mod scheme;
+mod batch;
+mod checksum;
+mod codec;
+pub mod deterministic;
+mod display_code;
+mod encoded;
+mod format;
+mod obfuscation;
+pub mod scheme;
+pub mod ulid;
+
+pub use batch::EncodedBatch;
+pub use codec::Codec;
+pub use display_code::DisplayCode;
+pub use encoded::EncodedId;
+pub use format::Format;
+pub use obfuscation::Obfuscator;
+pub use scheme::Scheme;
+pub use ulid::Ulid;
+
+#[cfg(feature = "derive")]
+pub use grapple_utils_derive::CUuidId;
 
 // Re-export uuid
 pub use uuid;
 
+/// Dependencies used by the code generated with derive macros.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    /// Random UUID, from the installed generator with the `test-util` feature.
+    #[inline]
+    pub fn new_v4() -> uuid::Uuid {
+        super::deterministic::new_v4()
+    }
+
+    /// Time-based UUID, from the installed generator with the `test-util` feature.
+    #[inline]
+    pub fn now_v7() -> uuid::Uuid {
+        super::deterministic::now_v7()
+    }
+}
+
+use core::any::Any;
+use core::str::FromStr;
+
 use derive_more::derive::From;
-use scheme::{get_scheme, Scheme};
-use uuid::Uuid;
+use scheme::get_scheme;
+use uuid::{Uuid, Variant, Version};
+
+/// All schemes enabled by features.
+const SCHEMES: &[CUuid] = &[
+    #[cfg(feature = "b58")]
+    CUuid::B58,
+    #[cfg(feature = "b64")]
+    CUuid::B64,
+    #[cfg(feature = "b32")]
+    CUuid::B32,
+];
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum CUuid {
     #[cfg(feature = "b58")]
     B58,
@@ -15,14 +68,37 @@ pub enum CUuid {
     B64,
     #[cfg(feature = "b32")]
     B32,
+    /// User-defined scheme.
+    Custom(&'static dyn Scheme),
 }
 
 impl CUuid {
+    /// Returns all built-in schemes enabled by features.
+    #[inline]
+    pub fn available() -> &'static [CUuid] {
+        SCHEMES
+    }
+
+    /// Returns the scheme used for encoding and decoding.
     #[inline]
-    fn get_scheme(&self) -> impl Scheme {
+    pub fn scheme(&self) -> &'static dyn Scheme {
         get_scheme(self)
     }
 
+    /// Returns the name of the scheme, e.g. `b58`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.scheme().name()
+    }
+
+    /// Returns the maximum length of an encoded UUID, e.g. 22 for `B58`.
+    ///
+    /// Ids encoded with `Codec::fixed_width` always have this length.
+    #[inline]
+    pub fn encoded_len(&self) -> usize {
+        self.scheme().encoded_len()
+    }
+
     /// Converts a given id into a BaseX encoded UUID.
     ///
     /// # Arguments
@@ -31,7 +107,7 @@ impl CUuid {
     /// # Returns
     /// A BaseX encoded string representation of the UUID.
     #[inline]
-    pub fn from(&self, id: impl Into<Uuid>) -> String {
+    pub fn from(&self, id: impl Into<Uuid>) -> EncodedId {
         self.encode(id.into())
     }
 
@@ -41,24 +117,68 @@ impl CUuid {
     /// * `id` - An object that can be tried to convert into a `uuid::Uuid`.
     ///
     /// # Returns
-    /// * `Ok(String)` if the conversion is successful.
+    /// * `Ok(EncodedId)` if the conversion is successful.
     /// * `Err(Error::InvalidFormat)` if the conversion fails.
     #[inline]
-    pub fn try_from(&self, id: impl TryInto<Uuid>) -> Result<String> {
+    pub fn try_from(&self, id: impl TryInto<Uuid>) -> Result<EncodedId> {
         let id = id.try_into().map_err(|_| Error::InvalidFormat)?;
         Ok(self.encode(id))
     }
 
     /// Generates a new time-based UUID and encodes it to BaseX.
     #[inline]
-    pub fn now_v7(&self) -> String {
-        self.encode(Uuid::now_v7())
+    pub fn now_v7(&self) -> EncodedId {
+        self.encode(deterministic::now_v7())
     }
 
     /// Generates a new random UUID and encodes it to BaseX.
     #[inline]
-    pub fn new_v4(&self) -> String {
-        self.encode(Uuid::new_v4())
+    pub fn new_v4(&self) -> EncodedId {
+        self.encode(deterministic::new_v4())
+    }
+
+    /// Generates a name-based UUID using MD5 and encodes it to BaseX.
+    ///
+    /// The same `namespace` and `name` always produce the same id.
+    ///
+    /// # Arguments
+    /// * `namespace` - A namespace UUID, e.g. `Uuid::NAMESPACE_DNS`.
+    /// * `name` - The name to derive the id from.
+    #[inline]
+    pub fn new_v3(&self, namespace: &Uuid, name: impl AsRef<[u8]>) -> EncodedId {
+        self.encode(Uuid::new_v3(namespace, name.as_ref()))
+    }
+
+    /// Generates a name-based UUID using SHA-1 and encodes it to BaseX.
+    ///
+    /// The same `namespace` and `name` always produce the same id.
+    ///
+    /// # Arguments
+    /// * `namespace` - A namespace UUID, e.g. `Uuid::NAMESPACE_DNS`.
+    /// * `name` - The name to derive the id from.
+    #[inline]
+    pub fn new_v5(&self, namespace: &Uuid, name: impl AsRef<[u8]>) -> EncodedId {
+        self.encode(Uuid::new_v5(namespace, name.as_ref()))
+    }
+
+    /// Generates a new time-ordered UUID and encodes it to BaseX.
+    ///
+    /// # Arguments
+    /// * `node_id` - A 6 byte node identifier, e.g. a MAC address.
+    #[inline]
+    pub fn now_v6(&self, node_id: &[u8; 6]) -> EncodedId {
+        self.encode(Uuid::now_v6(node_id))
+    }
+
+    /// Creates a custom UUID from the given bytes and encodes it to BaseX.
+    ///
+    /// Version and variant bits are overwritten, everything else is kept as is.
+    ///
+    /// # Arguments
+    /// * `buf` - The custom 16 bytes.
+    #[inline]
+    pub fn new_v8(&self, buf: [u8; 16]) -> EncodedId {
+        self.encode(Uuid::new_v8(buf))
     }
 
     /// Attempts to decode a BaseX encoded string into a UUID.
@@ -68,22 +188,24 @@ impl CUuid {
     ///
     /// # Returns
     /// * `Ok(uuid::Uuid)` if the decoding is successful.
-    /// * `Err(Error::FailToDecode)` if decoding fails.
-    /// * `Err(Error::InvalidFormat)` if the length is not 16 bytes.
+    /// * `Err(Error::InvalidChar)` if the value contains a character outside of the alphabet.
+    /// * `Err(Error::FailToDecode)` if the scheme fails to decode the value.
+    /// * `Err(Error::InvalidLength)` if the decoded value is not 16 bytes.
+    /// * `Err(Error::TooLong)` if the value is longer than `encoded_len`.
+    #[inline]
     pub fn try_decode(&self, value: &str) -> Result<Uuid> {
-        if value.is_empty() {
-            return Err(Error::InvalidFormat);
-        }
-
-        let bytes = self
-            .get_scheme()
-            .try_decode(value)
-            .map_err(|_| Error::FailToDecode)?;
+        decode_with(self.scheme(), value)
+    }
 
-        match bytes.len() {
-            16 => Uuid::from_slice(&bytes).map_err(|_| Error::InvalidFormat),
-            _ => Err(Error::InvalidFormat),
-        }
+    /// Attempts to decode a BaseX encoded string, accepting only the canonical encoding.
+    ///
+    /// # Returns
+    /// * `Ok(uuid::Uuid)` if the decoding is successful.
+    /// * `Err(Error::NonCanonical)` if encoding the id back gives a different string.
+    /// * `Err(_)` the same errors as `try_decode`.
+    #[inline]
+    pub fn try_decode_strict(&self, value: &str) -> Result<Uuid> {
+        self.codec().strict().try_decode(value)
     }
 
     /// Decode an encoded string into a UUID.
@@ -98,10 +220,139 @@ impl CUuid {
         self.try_decode(value).expect("Decode failed")
     }
 
+    /// Decodes an encoded string and returns the version of the UUID.
+    ///
+    /// # Returns
+    /// * `Ok(Some(uuid::Version))` if the version is known.
+    /// * `Ok(None)` if the version bits do not match any known version.
+    /// * `Err(_)` if decoding fails.
+    #[inline]
+    pub fn try_version(&self, value: &str) -> Result<Option<Version>> {
+        Ok(self.try_decode(value)?.get_version())
+    }
+
+    /// Decodes an encoded string and returns the variant of the UUID.
+    ///
+    /// # Returns
+    /// * `Ok(uuid::Variant)` if decoding is successful.
+    /// * `Err(_)` if decoding fails.
+    #[inline]
+    pub fn try_variant(&self, value: &str) -> Result<Variant> {
+        Ok(self.try_decode(value)?.get_variant())
+    }
+
     #[inline]
     /// Encode Uuid into string in specified format
-    fn encode(&self, id: Uuid) -> String {
-        self.get_scheme().encode(id)
+    fn encode(&self, id: Uuid) -> EncodedId {
+        self.scheme().encode_id(id.as_bytes())
+    }
+}
+
+// region:    --- Runtime selection
+
+impl PartialEq for CUuid {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CUuid::Custom(a), CUuid::Custom(b)) => {
+                // Unit structs have no distinct addresses, all their instances are the same
+                let zero_sized = core::mem::size_of_val(*a) == 0 && core::mem::size_of_val(*b) == 0;
+                match zero_sized {
+                    true => Any::type_id(*a) == Any::type_id(*b),
+                    false => core::ptr::addr_eq(*a, *b),
+                }
+            }
+            (CUuid::Custom(_), _) | (_, CUuid::Custom(_)) => false,
+            _ => self.name() == other.name(),
+        }
+    }
+}
+
+impl Eq for CUuid {}
+
+impl core::fmt::Display for CUuid {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        fmt.pad(self.name())
+    }
+}
+
+impl FromStr for CUuid {
+    type Err = Error;
+
+    /// Parses a built-in scheme by its name, e.g. `b58` or `base58`, ignoring case.
+    fn from_str(value: &str) -> Result<Self> {
+        let name = match value.trim().to_ascii_lowercase().as_str() {
+            "b58" | "base58" => "b58",
+            "b64" | "base64" => "b64",
+            "b32" | "base32" => "b32",
+            _ => return Err(scheme::Error::SchemeNotFound(value.to_string()).into()),
+        };
+
+        SCHEMES
+            .iter()
+            .find(|cuuid| cuuid.name() == name)
+            .copied()
+            .ok_or_else(|| scheme::Error::SchemeDisabled(name).into())
+    }
+}
+
+// endregion: --- Runtime selection
+
+/// Decodes a string into a UUID with the already resolved scheme.
+///
+/// Values padded up to `Scheme::encoded_len` are accepted, longer values are
+/// rejected before decoding, so every id has a bounded number of accepted strings.
+fn decode_with(scheme: &dyn Scheme, value: &str) -> Result<Uuid> {
+    check_len(scheme, value, scheme.encoded_len())?;
+
+    let bytes = match scheme.try_decode(value) {
+        Ok(bytes) => bytes,
+        Err(err) => return Err(decode_error(scheme, value, err)),
+    };
+
+    // Padding of fixed width ids decodes into leading zero bytes
+    let padding = bytes.len().saturating_sub(16);
+    if bytes.len() < 16 || bytes[..padding].iter().any(|b| *b != 0) {
+        return Err(Error::InvalidLength {
+            scheme: scheme.name(),
+            len: bytes.len(),
+        });
+    }
+
+    Uuid::from_slice(&bytes[padding..]).map_err(|_| Error::InvalidFormat)
+}
+
+/// Rejects values longer than `max` characters.
+fn check_len(scheme: &dyn Scheme, value: &str, max: usize) -> Result<()> {
+    let len = value.chars().count();
+
+    match len > max {
+        true => Err(Error::TooLong {
+            scheme: scheme.name(),
+            len,
+            max,
+        }),
+        false => Ok(()),
+    }
+}
+
+/// Points to the first character outside of the alphabet, if any, otherwise keeps the scheme error.
+fn decode_error(scheme: &dyn Scheme, value: &str, source: scheme::Error) -> Error {
+    let alphabet = scheme.alphabet();
+
+    match value
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !alphabet.contains(*c))
+    {
+        Some((position, char)) => Error::InvalidChar {
+            scheme: scheme.name(),
+            char,
+            position,
+        },
+        None => Error::FailToDecode {
+            scheme: scheme.name(),
+            source,
+        },
     }
 }
 
@@ -111,9 +362,37 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, From)]
 pub enum Error {
-    FailToEncode,
-    FailToDecode,
+    /// Scheme could not decode the value.
+    FailToDecode {
+        scheme: &'static str,
+        source: scheme::Error,
+    },
+    /// Value contains a character outside of the scheme alphabet, `position` counts characters.
+    InvalidChar {
+        scheme: &'static str,
+        char: char,
+        position: usize,
+    },
+    /// Value decoded into `len` bytes instead of 16.
+    InvalidLength {
+        scheme: &'static str,
+        len: usize,
+    },
+    /// Value has `len` characters, more than `max` of the scheme.
+    TooLong {
+        scheme: &'static str,
+        len: usize,
+        max: usize,
+    },
     InvalidFormat,
+    /// Value is the canonical encoding in several schemes.
+    AmbiguousFormat(Vec<Format>),
+    MonotonicOverflow,
+    ChecksumMismatch,
+    NonCanonical,
+    InvalidKey,
+    /// Failed items of a batch with their indexes.
+    Batch(Vec<(usize, Error)>),
 
     #[from]
     Scheme(scheme::Error),
@@ -122,11 +401,364 @@ pub enum Error {
 // region:    --- Error Boilerplate
 impl core::fmt::Display for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Error::FailToDecode { scheme, source } => {
+                write!(fmt, "Failed to decode `{scheme}` id: {source}")
+            }
+            Error::InvalidChar {
+                scheme,
+                char,
+                position,
+            } => write!(
+                fmt,
+                "Invalid character `{char}` at position {position} of `{scheme}` id"
+            ),
+            Error::InvalidLength { scheme, len } => {
+                write!(fmt, "`{scheme}` id decoded into {len} bytes, expected 16")
+            }
+            Error::TooLong { scheme, len, max } => {
+                write!(
+                    fmt,
+                    "`{scheme}` id has {len} characters, expected at most {max}"
+                )
+            }
+            Error::InvalidFormat => write!(fmt, "Invalid id format"),
+            Error::AmbiguousFormat(formats) => {
+                write!(fmt, "Id is valid in several formats: {formats:?}")
+            }
+            Error::MonotonicOverflow => {
+                write!(fmt, "Too many ids generated within the same millisecond")
+            }
+            Error::ChecksumMismatch => write!(fmt, "Check character does not match"),
+            Error::NonCanonical => write!(fmt, "Id is not in the canonical encoding"),
+            Error::InvalidKey => write!(fmt, "Obfuscation key must be at least 16 bytes"),
+            Error::Batch(errors) => {
+                write!(fmt, "{} ids failed to decode", errors.len())?;
+                for (index, err) in errors {
+                    write!(fmt, "; [{index}] {err}")?;
+                }
+                Ok(())
+            }
+            Error::Scheme(err) => write!(fmt, "{err}"),
+        }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::FailToDecode { source, .. } => Some(source),
+            Error::Scheme(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 // endregion: --- Error Boilerplate
 
 // endregion: --- Error
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "b64")]
+    const C: CUuid = CUuid::B64;
+    #[cfg(all(feature = "b58", not(feature = "b64")))]
+    const C: CUuid = CUuid::B58;
+    #[cfg(all(feature = "b32", not(any(feature = "b58", feature = "b64"))))]
+    const C: CUuid = CUuid::B32;
+
+    #[test]
+    fn test_new_v3_stable() -> Result<()> {
+        let first = C.new_v3(&Uuid::NAMESPACE_DNS, "example.com");
+        let second = C.new_v3(&Uuid::NAMESPACE_DNS, "example.com");
+
+        assert_eq!(first, second);
+        assert_eq!(
+            C.decode(&first),
+            Uuid::parse_str("9073926b-929f-31c2-abc9-fad77ae3e8eb").unwrap()
+        );
+        assert_eq!(C.try_version(&first)?, Some(Version::Md5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_v5_stable() -> Result<()> {
+        let first = C.new_v5(&Uuid::NAMESPACE_DNS, "example.com");
+        let second = C.new_v5(&Uuid::NAMESPACE_DNS, "example.com");
+
+        assert_eq!(first, second);
+        assert_eq!(
+            C.decode(&first),
+            Uuid::parse_str("cfbff0d1-9375-5685-968c-48ce8b15ae17").unwrap()
+        );
+        assert_eq!(C.try_version(&first)?, Some(Version::Sha1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_now_v6_version() -> Result<()> {
+        let encoded = C.now_v6(&[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(C.try_version(&encoded)?, Some(Version::SortMac));
+        assert_eq!(C.try_variant(&encoded)?, Variant::RFC4122);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_v8_version() -> Result<()> {
+        let encoded = C.new_v8([0xff; 16]);
+
+        assert_eq!(C.try_version(&encoded)?, Some(Version::Custom));
+        assert_eq!(C.try_variant(&encoded)?, Variant::RFC4122);
+
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct SchemeHex;
+
+    impl Scheme for SchemeHex {
+        fn encode(&self, content: &[u8]) -> String {
+            content.iter().map(|b| format!("{b:02x}")).collect()
+        }
+
+        fn alphabet(&self) -> &'static str {
+            "0123456789abcdef"
+        }
+
+        fn try_decode(&self, content: &str) -> scheme::Result<Vec<u8>> {
+            (0..content.len())
+                .step_by(2)
+                .map(|i| {
+                    content
+                        .get(i..i + 2)
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                        .ok_or_else(|| scheme::Error::Custom(format!("Invalid hex at {i}").into()))
+                })
+                .collect()
+        }
+    }
+
+    const HEX: CUuid = CUuid::Custom(&SchemeHex);
+
+    #[test]
+    fn test_custom_scheme() -> Result<()> {
+        let fx_id = Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap();
+
+        let encoded = HEX.from(fx_id);
+        assert_eq!(encoded, "019703702e2d76e38c693bb5e943b2a2");
+        assert_eq!(HEX.try_decode(&encoded)?, fx_id);
+
+        assert!(HEX.try_decode("zz").is_err());
+        assert_eq!(HEX.try_decode_strict(&encoded)?, fx_id);
+        assert!(matches!(
+            HEX.try_decode_strict(&encoded.to_uppercase()),
+            Err(Error::NonCanonical)
+        ));
+        assert_eq!(HEX.try_version(&HEX.now_v7())?, Some(Version::SortRand));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_and_display() -> Result<()> {
+        for cuuid in CUuid::available() {
+            let parsed: CUuid = cuuid.to_string().parse()?;
+            assert_eq!(parsed, *cuuid);
+        }
+
+        #[cfg(feature = "b64")]
+        assert_eq!(" Base64 ".parse::<CUuid>()?, CUuid::B64);
+        assert!("b16".parse::<CUuid>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "b32", feature = "b58", feature = "b64")))]
+    fn test_from_str_disabled() {
+        let disabled = ["b32", "b58", "b64"]
+            .into_iter()
+            .find(|name| CUuid::available().iter().all(|c| c.name() != *name))
+            .unwrap();
+
+        let err = disabled.parse::<CUuid>().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Scheme(scheme::Error::SchemeDisabled(_))
+        ));
+        assert!(err.to_string().contains("feature"));
+    }
+
+    #[test]
+    fn test_custom_eq() {
+        #[derive(Debug)]
+        struct SchemeOther;
+
+        impl Scheme for SchemeOther {
+            fn encode(&self, content: &[u8]) -> String {
+                SchemeHex.encode(content)
+            }
+
+            fn alphabet(&self) -> &'static str {
+                SchemeHex.alphabet()
+            }
+
+            fn try_decode(&self, content: &str) -> scheme::Result<Vec<u8>> {
+                SchemeHex.try_decode(content)
+            }
+        }
+
+        assert_eq!(HEX, HEX);
+        assert_eq!(HEX, CUuid::Custom(&SchemeHex));
+        assert_ne!(HEX, CUuid::Custom(&SchemeOther));
+        assert_eq!(HEX.to_string(), "custom");
+        assert!(CUuid::available().iter().all(|c| *c != HEX));
+    }
+
+    #[test]
+    fn test_custom_eq_instances() {
+        #[derive(Debug)]
+        struct SchemeCase {
+            alphabet: &'static str,
+        }
+
+        impl Scheme for SchemeCase {
+            fn encode(&self, content: &[u8]) -> String {
+                SchemeHex.encode(content)
+            }
+
+            fn alphabet(&self) -> &'static str {
+                self.alphabet
+            }
+
+            fn try_decode(&self, content: &str) -> scheme::Result<Vec<u8>> {
+                SchemeHex.try_decode(content)
+            }
+        }
+
+        static LOWER: SchemeCase = SchemeCase {
+            alphabet: "0123456789abcdef",
+        };
+        static UPPER: SchemeCase = SchemeCase {
+            alphabet: "0123456789ABCDEF",
+        };
+
+        assert_eq!(CUuid::Custom(&LOWER), CUuid::Custom(&LOWER));
+        assert_ne!(CUuid::Custom(&LOWER), CUuid::Custom(&UPPER));
+        assert_ne!(CUuid::Custom(&LOWER), HEX);
+    }
+
+    #[test]
+    fn test_version_of_v4_and_v7() -> Result<()> {
+        assert_eq!(C.try_version(&C.new_v4())?, Some(Version::Random));
+        assert_eq!(C.try_version(&C.now_v7())?, Some(Version::SortRand));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_invalid_char() {
+        let err = HEX
+            .try_decode("01970370-2e2d76e38c693bb5e943b2a")
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidChar {
+                scheme: "custom",
+                char: '-',
+                position: 8
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Invalid character `-` at position 8 of `custom` id"
+        );
+    }
+
+    #[test]
+    fn test_error_invalid_length() {
+        let err = HEX.try_decode("0197037f").unwrap_err();
+
+        assert!(matches!(err, Error::InvalidLength { len: 4, .. }));
+        assert_eq!(
+            err.to_string(),
+            "`custom` id decoded into 4 bytes, expected 16"
+        );
+        assert!(matches!(
+            HEX.try_decode(""),
+            Err(Error::InvalidLength { len: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_error_too_long() {
+        let err = HEX.try_decode(&"0".repeat(34)).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::TooLong {
+                len: 34,
+                max: 32,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "`custom` id has 34 characters, expected at most 32"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "b58")]
+    fn test_padding_b58() -> Result<()> {
+        let id = "CPSnYpT5QSYA2h1RwKSc1";
+
+        // Padded up to the encoded length only
+        assert_eq!(
+            CUuid::B58.try_decode(&format!("1{id}"))?,
+            CUuid::B58.decode(id)
+        );
+        assert!(matches!(
+            CUuid::B58.try_decode(&format!("111111{id}")),
+            Err(Error::TooLong { .. })
+        ));
+        assert!(CUuid::B58.try_decode(&"1".repeat(200)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "b64")]
+    fn test_padding_b64() {
+        // B64 ids always have the encoded length, so they are never padded
+        assert!(matches!(
+            CUuid::B64.try_decode("AAAAAZcDcC4tduOMaTu16UOyog"),
+            Err(Error::TooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error as _;
+
+        // Odd number of digits fails in the scheme itself
+        let err = HEX.try_decode("019").unwrap_err();
+
+        assert!(matches!(err, Error::FailToDecode { .. }));
+        assert!(err.source().is_some());
+        assert_eq!(
+            err.to_string(),
+            "Failed to decode `custom` id: Invalid hex at 2"
+        );
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,208 @@
+use uuid::Uuid;
+
+use super::{Error, Result};
+
+/// Minimal key length in bytes.
+const MIN_KEY_LEN: usize = 16;
+
+const ROUNDS: u64 = 8;
+
+/// Keyed reversible permutation of UUIDs.
+///
+/// Uses a Feistel network with SipHash-2-4 as round function, so without
+/// the key the obfuscated UUID does not show the original one, e.g. the
+/// timestamp of UUIDv7. Only the public representation changes, internal
+/// storage can keep the original ids.
+#[derive(Clone, Copy)]
+pub struct Obfuscator {
+    k0: u64,
+    k1: u64,
+}
+
+impl Obfuscator {
+    /// Creates an obfuscator from a secret key.
+    ///
+    /// # Arguments
+    /// * `key` - Secret key of at least 16 bytes, e.g. loaded with `envs::get_b64u_as_u8s`.
+    ///
+    /// # Returns
+    /// * `Ok(Obfuscator)` if the key is long enough.
+    /// * `Err(Error::InvalidKey)` if the key is shorter than 16 bytes.
+    pub fn new(key: impl AsRef<[u8]>) -> Result<Obfuscator> {
+        let key = key.as_ref();
+        if key.len() < MIN_KEY_LEN {
+            return Err(Error::InvalidKey);
+        }
+
+        // Derive round keys from the whole key, so keys of any length can be used
+        let words: Vec<u64> = key
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(word)
+            })
+            .collect();
+
+        Ok(Obfuscator {
+            k0: siphash24(0, 0, &words, key.len()),
+            k1: siphash24(0, 1, &words, key.len()),
+        })
+    }
+
+    /// Maps a UUID to an opaque-looking one.
+    pub fn obfuscate(&self, id: Uuid) -> Uuid {
+        let (mut left, mut right) = split(id);
+
+        for round in 0..ROUNDS {
+            (left, right) = (right, left ^ self.round(right, round));
+        }
+
+        join(left, right)
+    }
+
+    /// Maps an obfuscated UUID back to the original one.
+    pub fn reveal(&self, id: Uuid) -> Uuid {
+        let (mut left, mut right) = split(id);
+
+        for round in (0..ROUNDS).rev() {
+            (left, right) = (right ^ self.round(left, round), left);
+        }
+
+        join(left, right)
+    }
+
+    #[inline]
+    fn round(&self, half: u64, round: u64) -> u64 {
+        siphash24(self.k0, self.k1, &[half, round], 16)
+    }
+}
+
+impl core::fmt::Debug for Obfuscator {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        // Never print the keys
+        fmt.debug_struct("Obfuscator").finish_non_exhaustive()
+    }
+}
+
+#[inline]
+fn split(id: Uuid) -> (u64, u64) {
+    let value = id.as_u128();
+    ((value >> 64) as u64, value as u64)
+}
+
+#[inline]
+fn join(left: u64, right: u64) -> Uuid {
+    Uuid::from_u128((left as u128) << 64 | right as u128)
+}
+
+/// SipHash-2-4 over little-endian words, `len` is the message length in bytes.
+fn siphash24(k0: u64, k1: u64, words: &[u64], len: usize) -> u64 {
+    let mut v = [
+        k0 ^ 0x736f6d6570736575,
+        k1 ^ 0x646f72616e646f6d,
+        k0 ^ 0x6c7967656e657261,
+        k1 ^ 0x7465646279746573,
+    ];
+
+    let full = len / 8;
+    let tail = words.get(full).copied().unwrap_or_default();
+    let last = (len as u64) << 56 | tail;
+
+    for m in words[..full].iter().copied().chain([last]) {
+        v[3] ^= m;
+        sip_round(&mut v);
+        sip_round(&mut v);
+        v[0] ^= m;
+    }
+
+    v[2] ^= 0xff;
+    for _ in 0..4 {
+        sip_round(&mut v);
+    }
+
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+#[inline]
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    fn get_v7() -> Uuid {
+        Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap()
+    }
+
+    #[test]
+    fn test_siphash_vectors() {
+        // Reference vectors with key 00..0f
+        let k0 = u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]);
+        let k1 = u64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]);
+
+        assert_eq!(siphash24(k0, k1, &[], 0), 0x726fdb47dd0e0e31);
+        assert_eq!(
+            siphash24(k0, k1, &[u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7])], 8),
+            0x93f5f5799a932462
+        );
+    }
+
+    #[test]
+    fn test_obfuscate_reveal() -> Result<()> {
+        let obfuscator = Obfuscator::new(KEY)?;
+        let fx_id = get_v7();
+
+        let obfuscated = obfuscator.obfuscate(fx_id);
+
+        assert_ne!(obfuscated, fx_id);
+        assert_eq!(obfuscator.reveal(obfuscated), fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_obfuscate_hides_timestamp() -> Result<()> {
+        let obfuscator = Obfuscator::new(KEY)?;
+
+        // Ids with the same timestamp share no common prefix after obfuscation
+        let first = obfuscator.obfuscate(get_v7());
+        let second = obfuscator.obfuscate(Uuid::from_u128(get_v7().as_u128() + 1));
+
+        assert_ne!(first.as_bytes()[..6], second.as_bytes()[..6]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_different_keys() -> Result<()> {
+        let first = Obfuscator::new(KEY)?;
+        let second = Obfuscator::new(b"another secret key")?;
+
+        assert_ne!(first.obfuscate(get_v7()), second.obfuscate(get_v7()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_short_key() {
+        assert!(matches!(Obfuscator::new(b"short"), Err(Error::InvalidKey)));
+    }
+}
+
+// endregion: --- Tests
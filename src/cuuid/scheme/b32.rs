@@ -2,19 +2,42 @@ use crate::b32x;
 
 use super::Result;
 use super::Scheme;
+use crate::cuuid::EncodedId;
 
+/// Base32 HEX scheme without padding.
+#[derive(Debug)]
 pub struct SchemeB32;
 
 impl Scheme for SchemeB32 {
     #[inline]
-    fn encode(&self, content: impl AsRef<[u8]>) -> String {
+    fn encode(&self, content: &[u8]) -> String {
         b32x::encode(content)
     }
 
+    #[inline]
+    fn encode_id(&self, content: &[u8; 16]) -> EncodedId {
+        EncodedId::from_fn(|buf| b32x::encode_mut(content, buf))
+    }
+
     #[inline]
     fn try_decode(&self, content: &str) -> Result<Vec<u8>> {
         Ok(b32x::decode(content)?)
     }
+
+    #[inline]
+    fn alphabet(&self) -> &'static str {
+        "0123456789ABCDEFGHIJKLMNOPQRSTUV"
+    }
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "b32"
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        26
+    }
 }
 
 // region:    --- Tests
@@ -2,19 +2,59 @@ use crate::b58;
 
 use super::Result;
 use super::Scheme;
+use crate::cuuid::EncodedId;
 
+/// Base58 scheme.
+#[derive(Debug)]
 pub struct SchemeB58;
 
 impl Scheme for SchemeB58 {
     #[inline]
-    fn encode(&self, content: impl AsRef<[u8]>) -> String {
+    fn encode(&self, content: &[u8]) -> String {
         b58::encode(content)
     }
 
+    fn encode_id(&self, content: &[u8; 16]) -> EncodedId {
+        let alphabet = self.alphabet().as_bytes();
+        let zeros = content.iter().take_while(|b| **b == 0).count();
+        let mut value = u128::from_be_bytes(*content);
+
+        EncodedId::from_fn(|buf| {
+            // Write digits from the end, then move them after leading zeros
+            let mut start = buf.len();
+            while value > 0 {
+                start -= 1;
+                buf[start] = alphabet[(value % 58) as usize];
+                value /= 58;
+            }
+
+            let digits = buf.len() - start;
+            buf.copy_within(start.., zeros);
+            buf[..zeros].fill(alphabet[0]);
+
+            zeros + digits
+        })
+    }
+
     #[inline]
     fn try_decode(&self, content: &str) -> Result<Vec<u8>> {
         Ok(b58::decode(content)?)
     }
+
+    #[inline]
+    fn alphabet(&self) -> &'static str {
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+    }
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "b58"
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        22
+    }
 }
 
 // region:    --- Tests
@@ -72,6 +112,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_uuid_leading_zeros_encode() -> Result<()> {
+        let fx_id = Uuid::from_u128(0x00000370_2e2d_76e3_8c69_3bb5e943b2a2);
+
+        let encoded = C.encode(fx_id);
+
+        assert_eq!(encoded, crate::b58::encode(fx_id.as_bytes()));
+        assert_eq!(C.encode(Uuid::nil()), "1111111111111111");
+
+        Ok(())
+    }
+
     #[test]
     fn test_uuid_v4_decode() -> Result<()> {
         let fx_id = get_v4();
@@ -1,19 +1,42 @@
 use super::Result;
 use super::Scheme;
 use crate::b64u;
+use crate::cuuid::EncodedId;
 
+/// URL-safe Base64 scheme without padding.
+#[derive(Debug)]
 pub struct SchemeB64;
 
 impl Scheme for SchemeB64 {
     #[inline]
-    fn encode(&self, content: impl AsRef<[u8]>) -> String {
+    fn encode(&self, content: &[u8]) -> String {
         b64u::encode(content)
     }
 
+    #[inline]
+    fn encode_id(&self, content: &[u8; 16]) -> EncodedId {
+        EncodedId::from_fn(|buf| b64u::encode_mut(content, buf))
+    }
+
     #[inline]
     fn try_decode(&self, content: &str) -> Result<Vec<u8>> {
         Ok(b64u::decode(content)?)
     }
+
+    #[inline]
+    fn alphabet(&self) -> &'static str {
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+    }
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "b64"
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        22
+    }
 }
 
 // region:    --- Tests
@@ -0,0 +1,134 @@
+use super::Scheme;
+use super::{Error, Result};
+use crate::cuuid::ulid::{decode_char, ALPHABET};
+
+/// Crockford's Base32 scheme, the alphabet of ULID.
+///
+/// The alphabet has a single case and no `I`, `L`, `O` and `U`, so codes are
+/// easy to read aloud and type. Decoding ignores case and reads `I`, `L` as `1`
+/// and `O` as `0`. Bytes are encoded as a big-endian number, so a UUID is
+/// encoded into the same 26 characters as the equal ULID.
+#[derive(Debug)]
+pub struct SchemeCrockford;
+
+impl Scheme for SchemeCrockford {
+    fn encode(&self, content: &[u8]) -> String {
+        let len = (content.len() * 8).div_ceil(5);
+        let mut encoded = String::with_capacity(len);
+        // Leading zero bits to fill the first character
+        let mut bits = len * 5 - content.len() * 8;
+        let mut acc: u32 = 0;
+
+        for byte in content {
+            acc = acc << 8 | *byte as u32;
+            bits += 8;
+
+            while bits >= 5 {
+                bits -= 5;
+                encoded.push(ALPHABET.as_bytes()[(acc >> bits) as usize & 0x1f] as char);
+            }
+            acc &= (1 << bits) - 1;
+        }
+
+        encoded
+    }
+
+    fn try_decode(&self, content: &str) -> Result<Vec<u8>> {
+        let len = content.chars().count();
+        let mut decoded = Vec::with_capacity(len * 5 / 8);
+        // Leading bits of the first character that must be zero
+        let mut padding = len * 5 - len * 5 / 8 * 8;
+        let mut bits = 0;
+        let mut acc: u32 = 0;
+
+        for (position, c) in content.chars().enumerate() {
+            let digit = u8::try_from(c).ok().and_then(decode_char).ok_or_else(|| {
+                Error::Custom(format!("Invalid character `{c}` at {position}").into())
+            })?;
+            acc = acc << 5 | digit as u32;
+            bits += 5;
+
+            if padding > 0 && bits >= padding {
+                if acc >> (bits - padding) != 0 {
+                    return Err(Error::Custom("Value overflows the decoded bytes".into()));
+                }
+                bits -= padding;
+                padding = 0;
+            }
+
+            while padding == 0 && bits >= 8 {
+                bits -= 8;
+                decoded.push((acc >> bits) as u8);
+                acc &= (1 << bits) - 1;
+            }
+        }
+
+        Ok(decoded)
+    }
+
+    #[inline]
+    fn alphabet(&self) -> &'static str {
+        ALPHABET
+    }
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "crockford"
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        26
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::cuuid::{CUuid, Result, Ulid};
+
+    const C: CUuid = CUuid::Custom(&SchemeCrockford);
+
+    fn get_v7() -> Uuid {
+        Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap()
+    }
+
+    #[test]
+    fn test_uuid_encode_as_ulid() -> Result<()> {
+        let fx_id = get_v7();
+
+        let encoded = C.from(fx_id);
+
+        assert_eq!(encoded, Ulid::from(fx_id).encode());
+        assert_eq!(C.try_decode(&encoded)?, fx_id);
+        assert_eq!(C.try_decode(&encoded.to_lowercase())?, fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_bytes() {
+        for len in 0..=16usize {
+            let content: Vec<u8> = (0..len).map(|i| 0xff - i as u8).collect();
+
+            let encoded = SchemeCrockford.encode(&content);
+
+            assert_eq!(encoded.len(), (len * 8).div_ceil(5));
+            assert_eq!(SchemeCrockford.try_decode(&encoded).unwrap(), content);
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(SchemeCrockford.try_decode("0U").is_err());
+        // 2 characters carry 10 bits, the first 2 must be zero
+        assert!(SchemeCrockford.try_decode("ZZ").is_err());
+        assert_eq!(SchemeCrockford.try_decode("7Z").unwrap(), [0xff]);
+    }
+}
+
+// endregion: --- Tests
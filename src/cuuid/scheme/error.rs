@@ -5,20 +5,48 @@ pub type Result<T> = core::result::Result<T, Error>;
 #[derive(Debug, From)]
 pub enum Error {
     SchemeNotFound(String),
+    SchemeDisabled(&'static str),
 
     #[cfg(feature = "b58")]
     Base58(crate::b58::Error),
 
     #[cfg(any(feature = "b64", feature = "b32"))]
     BaseX(crate::base_x::Error),
+
+    /// Error produced by a custom scheme.
+    Custom(Box<dyn std::error::Error + Send + Sync>),
 }
 
 // region:    --- Error Boilerplate
 impl core::fmt::Display for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Error::SchemeNotFound(name) => write!(fmt, "Unknown scheme `{name}`"),
+            Error::SchemeDisabled(name) => {
+                write!(
+                    fmt,
+                    "Scheme `{name}` is disabled, enable the `{name}` feature"
+                )
+            }
+            #[cfg(feature = "b58")]
+            Error::Base58(err) => write!(fmt, "{err}"),
+            #[cfg(any(feature = "b64", feature = "b32"))]
+            Error::BaseX(err) => write!(fmt, "{err}"),
+            Error::Custom(err) => write!(fmt, "{err}"),
+        }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "b58")]
+            Error::Base58(err) => Some(err),
+            #[cfg(any(feature = "b64", feature = "b32"))]
+            Error::BaseX(err) => Some(err),
+            Error::Custom(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
 // endregion: --- Error Boilerplate
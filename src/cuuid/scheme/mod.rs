@@ -5,41 +5,76 @@ mod b58;
 #[cfg(feature = "b64")]
 mod b64;
 
+mod crockford;
 mod error;
 
+#[cfg(feature = "b32")]
+pub use b32::SchemeB32;
+#[cfg(feature = "b58")]
+pub use b58::SchemeB58;
+#[cfg(feature = "b64")]
+pub use b64::SchemeB64;
+pub use crockford::SchemeCrockford;
 pub use error::{Error, Result};
 
-use enum_dispatch::enum_dispatch;
+use super::{CUuid, EncodedId};
 
-use super::CUuid;
+/// Encoding used by `CUuid` to turn UUID bytes into a string and back.
+///
+/// The trait is object safe, so custom encodings can be plugged in
+/// with `CUuid::Custom`. Custom schemes are equal if they are the same
+/// instance, instances of a unit struct are all equal.
+pub trait Scheme: core::any::Any + core::fmt::Debug + Send + Sync {
+    /// Encodes bytes into a string.
+    fn encode(&self, content: &[u8]) -> String;
 
-#[enum_dispatch]
-pub trait Scheme {
-    fn encode(&self, content: impl AsRef<[u8]>) -> String;
+    /// Encodes UUID bytes into a stack allocated string.
+    ///
+    /// Defaults to `encode`, schemes should override it to avoid heap allocation.
+    #[inline]
+    fn encode_id(&self, content: &[u8; 16]) -> EncodedId {
+        EncodedId::from(self.encode(content))
+    }
+
+    /// Decodes a string into bytes.
+    ///
+    /// # Panics
+    /// This will panic if decoding fails.
     #[inline]
     fn decode(&self, content: &str) -> Vec<u8> {
         self.try_decode(content).expect("Decode failed")
     }
+
+    /// Attempts to decode a string into bytes.
     fn try_decode(&self, content: &str) -> Result<Vec<u8>>;
-}
 
-#[enum_dispatch(Scheme)]
-pub enum SchemeDispatcher {
-    #[cfg(feature = "b32")]
-    B32(b32::SchemeB32),
-    #[cfg(feature = "b58")]
-    B58(b58::SchemeB58),
-    #[cfg(feature = "b64")]
-    B64(b64::SchemeB64),
+    /// Returns all characters the scheme encodes into, ordered by their digit value.
+    fn alphabet(&self) -> &'static str;
+
+    /// Returns the name of the scheme, used by `CUuid` display.
+    #[inline]
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    /// Returns the maximum number of characters of an encoded UUID.
+    ///
+    /// Defaults to the length of the encoded maximum UUID, schemes should
+    /// override it if shorter values may encode longer.
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        self.encode(&[u8::MAX; 16]).chars().count()
+    }
 }
 
-pub fn get_scheme(cuuid: &CUuid) -> impl Scheme {
+pub(super) fn get_scheme(cuuid: &CUuid) -> &'static dyn Scheme {
     match cuuid {
         #[cfg(feature = "b32")]
-        CUuid::B32 => SchemeDispatcher::B32(b32::SchemeB32),
+        CUuid::B32 => &SchemeB32,
         #[cfg(feature = "b58")]
-        CUuid::B58 => SchemeDispatcher::B58(b58::SchemeB58),
+        CUuid::B58 => &SchemeB58,
         #[cfg(feature = "b64")]
-        CUuid::B64 => SchemeDispatcher::B64(b64::SchemeB64),
+        CUuid::B64 => &SchemeB64,
+        CUuid::Custom(scheme) => *scheme,
     }
 }
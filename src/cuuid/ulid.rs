@@ -0,0 +1,293 @@
+use uuid::Uuid;
+
+use super::deterministic::{self, now_ms};
+use super::{Error, Result};
+
+/// Crockford's Base32 alphabet used by ULID.
+pub(super) const ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Length of the encoded ULID.
+pub const ULID_LEN: usize = 26;
+
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+/// Universally Unique Lexicographically Sortable Identifier.
+///
+/// Has the same 128 bit size as `uuid::Uuid`, so both types convert
+/// into each other without losing anything. The 48 bit millisecond
+/// timestamp takes the same place as in UUIDv7, so `Ulid::from(Uuid::now_v7())`
+/// is a valid ULID with the correct creation time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Ulid(u128);
+
+impl Ulid {
+    /// Generates a new ULID with the current time and random bits.
+    pub fn new() -> Ulid {
+        Ulid::from_parts(now_ms(), random())
+    }
+
+    /// Generates a new ULID that is also a valid UUIDv7.
+    #[inline]
+    pub fn now_v7() -> Ulid {
+        Ulid::from(deterministic::now_v7())
+    }
+
+    /// Creates a ULID from a millisecond timestamp and random bits.
+    ///
+    /// Only the lower 48 bits of `timestamp_ms` and 80 bits of `random` are used.
+    pub fn from_parts(timestamp_ms: u64, random: u128) -> Ulid {
+        let timestamp = (timestamp_ms as u128) & ((1 << 48) - 1);
+        Ulid(timestamp << RANDOM_BITS | random & RANDOM_MASK)
+    }
+
+    /// Returns the millisecond timestamp of the ULID.
+    #[inline]
+    pub fn timestamp_ms(&self) -> u64 {
+        (self.0 >> RANDOM_BITS) as u64
+    }
+
+    /// Returns the 80 random bits of the ULID.
+    #[inline]
+    pub fn random(&self) -> u128 {
+        self.0 & RANDOM_MASK
+    }
+
+    /// Returns `true` if the ULID is also a valid UUIDv7.
+    #[inline]
+    pub fn is_v7_compatible(&self) -> bool {
+        let id = self.to_uuid();
+        id.get_version_num() == 7 && id.get_variant() == uuid::Variant::RFC4122
+    }
+
+    /// Converts the ULID into a `uuid::Uuid` with the same bits.
+    #[inline]
+    pub fn to_uuid(&self) -> Uuid {
+        Uuid::from_u128(self.0)
+    }
+
+    /// Encodes the ULID into a 26 character Crockford's Base32 string.
+    pub fn encode(&self) -> String {
+        (0..ULID_LEN)
+            .rev()
+            .map(|i| ALPHABET.as_bytes()[((self.0 >> (i * 5)) & 0x1f) as usize] as char)
+            .collect()
+    }
+
+    /// Attempts to decode a Crockford's Base32 string into a ULID.
+    ///
+    /// Decoding is case-insensitive, `I` and `L` are read as `1`, `O` as `0`.
+    ///
+    /// # Returns
+    /// * `Ok(Ulid)` if the decoding is successful.
+    /// * `Err(Error::InvalidFormat)` if the length is not 26 or the value overflows 128 bits.
+    /// * `Err(Error::InvalidChar)` if the string contains invalid characters.
+    pub fn try_decode(value: &str) -> Result<Ulid> {
+        if value.len() != ULID_LEN {
+            return Err(Error::InvalidFormat);
+        }
+
+        let mut result: u128 = 0;
+        for (i, c) in value.chars().enumerate() {
+            let digit = u8::try_from(c)
+                .ok()
+                .and_then(decode_char)
+                .ok_or(Error::InvalidChar {
+                    scheme: "ulid",
+                    char: c,
+                    position: i,
+                })?;
+            // The first character carries only 3 bits
+            if i == 0 && digit > 7 {
+                return Err(Error::InvalidFormat);
+            }
+            result = result << 5 | digit as u128;
+        }
+
+        Ok(Ulid(result))
+    }
+}
+
+impl From<Uuid> for Ulid {
+    #[inline]
+    fn from(id: Uuid) -> Self {
+        Ulid(id.as_u128())
+    }
+}
+
+impl From<Ulid> for Uuid {
+    #[inline]
+    fn from(id: Ulid) -> Self {
+        id.to_uuid()
+    }
+}
+
+impl core::fmt::Display for Ulid {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(fmt, "{}", self.encode())
+    }
+}
+
+impl core::str::FromStr for Ulid {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Ulid::try_decode(value)
+    }
+}
+
+/// Generator of monotonic ULIDs.
+///
+/// ULIDs created within the same millisecond reuse the random bits
+/// of the previous one incremented by one, so they always sort in
+/// the order of creation.
+#[derive(Debug, Default)]
+pub struct Generator {
+    last: Option<Ulid>,
+}
+
+impl Generator {
+    /// Creates a new generator.
+    pub fn new() -> Generator {
+        Generator::default()
+    }
+
+    /// Generates the next monotonic ULID.
+    ///
+    /// # Returns
+    /// * `Ok(Ulid)` if the generation is successful.
+    /// * `Err(Error::MonotonicOverflow)` if the random bits overflow within one millisecond.
+    pub fn generate(&mut self) -> Result<Ulid> {
+        self.generate_at(now_ms())
+    }
+
+    /// Generates the next monotonic ULID for the given millisecond timestamp.
+    pub fn generate_at(&mut self, timestamp_ms: u64) -> Result<Ulid> {
+        let ulid = match self.last {
+            Some(last) if last.timestamp_ms() >= timestamp_ms => {
+                if last.random() == RANDOM_MASK {
+                    return Err(Error::MonotonicOverflow);
+                }
+                Ulid(last.0 + 1)
+            }
+            _ => Ulid::from_parts(timestamp_ms, random()),
+        };
+
+        self.last = Some(ulid);
+        Ok(ulid)
+    }
+}
+
+/// Decodes a Crockford's Base32 digit, ignoring case and reading `I`, `L` as `1`, `O` as `0`.
+pub(super) fn decode_char(c: u8) -> Option<u8> {
+    let c = match c.to_ascii_uppercase() {
+        b'I' | b'L' => b'1',
+        b'O' => b'0',
+        c => c,
+    };
+    ALPHABET.bytes().position(|a| a == c).map(|p| p as u8)
+}
+
+/// Takes 80 random bits from UUIDv4, skipping its version and variant bits.
+fn random() -> u128 {
+    let bits = deterministic::new_v4().as_u128();
+    let low = bits & ((1 << 62) - 1);
+    let high = (bits >> 80) & ((1 << 18) - 1);
+    high << 62 | low
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ULID: &str = "01JW1Q0BHDEVHRRT9VPQMM7CN2";
+
+    fn get_v7() -> Uuid {
+        Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap()
+    }
+
+    #[test]
+    fn test_ulid_from_uuid_v7() -> Result<()> {
+        let fx_id = get_v7();
+
+        let ulid = Ulid::from(fx_id);
+
+        assert_eq!(ulid.encode(), ULID);
+        assert_eq!(ulid.timestamp_ms(), 1748109372973);
+        assert!(ulid.is_v7_compatible());
+        assert_eq!(Uuid::from(ulid), fx_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ulid_decode() -> Result<()> {
+        let ulid = Ulid::try_decode(ULID)?;
+        assert_eq!(ulid.to_uuid(), get_v7());
+
+        let lowercase = Ulid::try_decode(&ULID.to_lowercase())?;
+        assert_eq!(lowercase, ulid);
+
+        let ambiguous = Ulid::try_decode("OlJW1Q0BHDEVHRRT9VPQMM7CN2")?;
+        assert_eq!(ambiguous, ulid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ulid_decode_invalid() {
+        assert!(Ulid::try_decode("").is_err());
+        assert!(Ulid::try_decode("01JW1Q0BHDEVHRRT9VPQMM7CN").is_err());
+        assert!(Ulid::try_decode("01JW1Q0BHDEVHRRT9VPQMM7CNU").is_err());
+        assert!(Ulid::try_decode("81JW1Q0BHDEVHRRT9VPQMM7CN2").is_err());
+    }
+
+    #[test]
+    fn test_ulid_roundtrip() -> Result<()> {
+        let ulid = Ulid::new();
+
+        assert_eq!(Ulid::try_decode(&ulid.encode())?, ulid);
+        assert_eq!(Ulid::from(ulid.to_uuid()), ulid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ulid_now_v7() {
+        let ulid = Ulid::now_v7();
+
+        assert!(ulid.is_v7_compatible());
+        assert!(!Ulid::from_parts(ulid.timestamp_ms(), 0).is_v7_compatible());
+    }
+
+    #[test]
+    fn test_generator_monotonic() -> Result<()> {
+        let mut generator = Generator::new();
+
+        let first = generator.generate_at(1000)?;
+        let second = generator.generate_at(1000)?;
+        let third = generator.generate_at(1001)?;
+
+        assert_eq!(second.random(), first.random() + 1);
+        assert!(first < second && second < third);
+        assert_eq!(third.timestamp_ms(), 1001);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generator_overflow() {
+        let mut generator = Generator {
+            last: Some(Ulid::from_parts(1000, RANDOM_MASK)),
+        };
+
+        assert!(matches!(
+            generator.generate_at(1000),
+            Err(Error::MonotonicOverflow)
+        ));
+    }
+}
+
+// endregion: --- Tests
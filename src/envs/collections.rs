@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use super::keys::split_entries;
+use super::{Envs, Error, KeysFormat, Result};
+
+/// Pair parsed from a variable: index, key as written, parsed key and value.
+type ParsedPair<K, V> = (usize, String, K, V);
+
+impl Envs {
+    /// Gets a list of values from an environment variable, e.g. `HOSTS=a,b,c`.
+    ///
+    /// Items are trimmed, separators are literal inside double quotes or after `\`.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    /// - `separator`: The separator of items, e.g. `,`.
+    ///
+    /// # Returns
+    /// Parsed items in order, or `Error::WrongItem` with the index of an empty
+    /// or malformed item.
+    pub fn get_list<T: FromStr>(&self, name: impl AsRef<str>, separator: char) -> Result<Vec<T>> {
+        let name = name.as_ref();
+        let value = self.get(name)?;
+        if value.is_empty() {
+            return Err(Error::EmptyEnv(self.full_name(name)));
+        }
+
+        let error = |index: usize, reason: &'static str| Error::WrongItem {
+            name: self.full_name(name),
+            index,
+            reason,
+        };
+
+        split_entries(&value, separator, None)
+            .map_err(|(index, reason)| error(index, reason))?
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, item))| match item.is_empty() {
+                true => Err(error(index, "empty item")),
+                false => item
+                    .parse()
+                    .map_err(|_| error(index, "item can not be parsed")),
+            })
+            .collect()
+    }
+
+    /// Gets key-value pairs in order from an environment variable, parsing keys and values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// Parsed pairs in the order of the variable, or `Error::WrongPair`
+    /// with the index of the pair that can not be parsed.
+    #[inline]
+    pub fn get_pairs<K: FromStr, V: FromStr>(&self, name: impl AsRef<str>) -> Result<Vec<(K, V)>> {
+        self.get_pairs_with(name, &KeysFormat::default())
+    }
+
+    /// Gets key-value pairs in the format in order from an environment variable,
+    /// parsing keys and values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs, e.g. with other separators.
+    pub fn get_pairs_with<K: FromStr, V: FromStr>(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<Vec<(K, V)>> {
+        let pairs = self.parse_pairs(name.as_ref(), format)?;
+        Ok(pairs
+            .into_iter()
+            .map(|(_, _, key, value)| (key, value))
+            .collect())
+    }
+
+    /// Gets a map from an environment variable, parsing keys and values,
+    /// e.g. ports with weights `8080:1,8081:2`.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// A `HashMap<K, V>`, `Error::WrongPair` with the index of the pair that can not
+    /// be parsed, or `Error::DuplicateKey` if parsed keys are equal, e.g. `1` and `01`.
+    #[inline]
+    pub fn get_map<K, V>(&self, name: impl AsRef<str>) -> Result<HashMap<K, V>>
+    where
+        K: FromStr + Eq + Hash,
+        V: FromStr,
+    {
+        self.get_map_with(name, &KeysFormat::default())
+    }
+
+    /// Gets a map in the format from an environment variable, parsing keys and values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs, e.g. with other separators.
+    ///
+    /// # Returns
+    /// A `HashMap<K, V>`, errors are the same as of `get_map`, equal keys are allowed
+    /// with `KeysFormat::allow_duplicates` and the last value wins.
+    pub fn get_map_with<K, V>(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<HashMap<K, V>>
+    where
+        K: FromStr + Eq + Hash,
+        V: FromStr,
+    {
+        let name = name.as_ref();
+        let mut map = HashMap::new();
+
+        for (index, raw, key, value) in self.parse_pairs(name, format)? {
+            if map.insert(key, value).is_some() && !format.allows_duplicates() {
+                return Err(self.duplicate_error(name, raw, index));
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Gets a map sorted by keys from an environment variable, parsing keys and values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// A `BTreeMap<K, V>`, errors are the same as of `get_map`.
+    #[inline]
+    pub fn get_btree_map<K, V>(&self, name: impl AsRef<str>) -> Result<BTreeMap<K, V>>
+    where
+        K: FromStr + Ord,
+        V: FromStr,
+    {
+        self.get_btree_map_with(name, &KeysFormat::default())
+    }
+
+    /// Gets a map sorted by keys in the format from an environment variable,
+    /// parsing keys and values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs, e.g. with other separators.
+    ///
+    /// # Returns
+    /// A `BTreeMap<K, V>`, errors are the same as of `get_map`, equal keys are allowed
+    /// with `KeysFormat::allow_duplicates` and the last value wins.
+    pub fn get_btree_map_with<K, V>(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<BTreeMap<K, V>>
+    where
+        K: FromStr + Ord,
+        V: FromStr,
+    {
+        let name = name.as_ref();
+        let mut map = BTreeMap::new();
+
+        for (index, raw, key, value) in self.parse_pairs(name, format)? {
+            if map.insert(key, value).is_some() && !format.allows_duplicates() {
+                return Err(self.duplicate_error(name, raw, index));
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn parse_pairs<K: FromStr, V: FromStr>(
+        &self,
+        name: &str,
+        format: &KeysFormat,
+    ) -> Result<Vec<ParsedPair<K, V>>> {
+        self.read_keys_with(name, format)?
+            .into_iter()
+            .map(|(index, raw, value)| {
+                let key = raw
+                    .parse()
+                    .map_err(|_| self.pair_error(name, index, "key can not be parsed"))?;
+                let value = value
+                    .parse()
+                    .map_err(|_| self.pair_error(name, index, "value can not be parsed"))?;
+
+                Ok((index, raw, key, value))
+            })
+            .collect()
+    }
+
+    fn duplicate_error(&self, name: &str, key: String, index: usize) -> Error {
+        Error::DuplicateKey {
+            name: self.full_name(name),
+            key,
+            index,
+        }
+    }
+}
+
+/// Gets a list of values from an environment variable, e.g. `HOSTS=a,b,c`.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+/// - `separator`: The separator of items, e.g. `,`.
+///
+/// # Returns
+/// Parsed items in order, or `Error::WrongItem` with the index of an empty
+/// or malformed item.
+#[inline]
+pub fn get_list<T: FromStr>(name: impl AsRef<str>, separator: char) -> Result<Vec<T>> {
+    Envs::global().get_list(name, separator)
+}
+
+/// Gets key-value pairs in order from an environment variable, parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+#[inline]
+pub fn get_pairs<K: FromStr, V: FromStr>(name: impl AsRef<str>) -> Result<Vec<(K, V)>> {
+    Envs::global().get_pairs(name)
+}
+
+/// Gets key-value pairs in the format in order from an environment variable,
+/// parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `format`: The grammar of the pairs, e.g. with other separators.
+#[inline]
+pub fn get_pairs_with<K: FromStr, V: FromStr>(
+    name: impl AsRef<str>,
+    format: &KeysFormat,
+) -> Result<Vec<(K, V)>> {
+    Envs::global().get_pairs_with(name, format)
+}
+
+/// Gets a map from an environment variable, parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+#[inline]
+pub fn get_map<K, V>(name: impl AsRef<str>) -> Result<HashMap<K, V>>
+where
+    K: FromStr + Eq + Hash,
+    V: FromStr,
+{
+    Envs::global().get_map(name)
+}
+
+/// Gets a map in the format from an environment variable, parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `format`: The grammar of the pairs, e.g. with other separators.
+#[inline]
+pub fn get_map_with<K, V>(name: impl AsRef<str>, format: &KeysFormat) -> Result<HashMap<K, V>>
+where
+    K: FromStr + Eq + Hash,
+    V: FromStr,
+{
+    Envs::global().get_map_with(name, format)
+}
+
+/// Gets a map sorted by keys from an environment variable, parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+#[inline]
+pub fn get_btree_map<K, V>(name: impl AsRef<str>) -> Result<BTreeMap<K, V>>
+where
+    K: FromStr + Ord,
+    V: FromStr,
+{
+    Envs::global().get_btree_map(name)
+}
+
+/// Gets a map sorted by keys in the format from an environment variable,
+/// parsing keys and values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `format`: The grammar of the pairs, e.g. with other separators.
+#[inline]
+pub fn get_btree_map_with<K, V>(
+    name: impl AsRef<str>,
+    format: &KeysFormat,
+) -> Result<BTreeMap<K, V>>
+where
+    K: FromStr + Ord,
+    V: FromStr,
+{
+    Envs::global().get_btree_map_with(name, format)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envs() -> Envs {
+        Envs::from_map([
+            ("LIST_HOSTS", "a, b ,\"c,d\""),
+            ("LIST_PORTS", "80,x,443"),
+            ("LIST_TRAILING", "1,2,"),
+            ("MAP_WEIGHTS", "8081:2,8080:1"),
+            ("MAP_INVALID_KEY", "8080:1,port:2"),
+            ("MAP_DUPLICATE", "1:a,01:b"),
+            ("MAP_FORMAT", "8081=2; 8080=1;"),
+        ])
+    }
+
+    #[test]
+    fn test_get_list() -> Result<()> {
+        let envs = envs();
+
+        let hosts: Vec<String> = envs.get_list("LIST_HOSTS", ',')?;
+        assert_eq!(hosts, ["a", "b", "c,d"]);
+
+        let result = envs.get_list::<u16>("LIST_PORTS", ',');
+        assert!(matches!(result, Err(Error::WrongItem { index: 1, .. })));
+
+        let result = envs.get_list::<u16>("LIST_TRAILING", ',');
+        assert!(matches!(
+            result,
+            Err(Error::WrongItem {
+                index: 2,
+                reason: "empty item",
+                ..
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_map() -> Result<()> {
+        let envs = envs();
+
+        let weights: HashMap<u16, u8> = envs.get_map("MAP_WEIGHTS")?;
+        assert_eq!(weights[&8080], 1);
+
+        // Order of the variable and sorted by keys
+        let pairs: Vec<(u16, u8)> = envs.get_pairs("MAP_WEIGHTS")?;
+        assert_eq!(pairs, [(8081, 2), (8080, 1)]);
+        let sorted: BTreeMap<u16, u8> = envs.get_btree_map("MAP_WEIGHTS")?;
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            [(8080, 1), (8081, 2)]
+        );
+
+        let result = envs.get_map::<u16, u8>("MAP_INVALID_KEY");
+        assert!(matches!(
+            result,
+            Err(Error::WrongPair {
+                index: 1,
+                reason: "key can not be parsed",
+                ..
+            })
+        ));
+
+        // Custom separators
+        let format = KeysFormat::new()
+            .pair_separator(';')
+            .key_separator('=')
+            .skip_empty(true);
+        let weights: HashMap<u16, u8> = envs.get_map_with("MAP_FORMAT", &format)?;
+        assert_eq!(weights[&8081], 2);
+        let sorted: BTreeMap<u16, u8> = envs.get_btree_map_with("MAP_FORMAT", &format)?;
+        assert_eq!(sorted.keys().copied().collect::<Vec<_>>(), [8080, 8081]);
+
+        // Keys are equal after parsing
+        let result = envs.get_btree_map::<u8, String>("MAP_DUPLICATE");
+        assert!(matches!(
+            result,
+            Err(Error::DuplicateKey { key, index: 1, .. }) if key == "01"
+        ));
+
+        // The last value wins
+        let format = KeysFormat::new().allow_duplicates(true);
+        let map: HashMap<u8, String> = envs.get_map_with("MAP_DUPLICATE", &format)?;
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&1], "b");
+        let sorted: BTreeMap<u8, String> = envs.get_btree_map_with("MAP_DUPLICATE", &format)?;
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            [(1, "b".to_string())]
+        );
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,102 @@
+use super::{Error, Result};
+
+/// Collects errors of several getters to report all invalid variables at once.
+///
+/// Getters are checked one by one with `check`, which keeps the error and
+/// returns `None` instead of stopping at the first one. `finish` returns
+/// `Error::Multiple` with all collected errors, its `Display` is a readable report.
+#[derive(Debug, Default)]
+pub struct Collector {
+    errors: Vec<Error>,
+}
+
+impl Collector {
+    /// Creates a collector without errors.
+    #[inline]
+    pub fn new() -> Collector {
+        Collector::default()
+    }
+
+    /// Returns the value, or collects the error and returns `None`.
+    ///
+    /// Errors of a nested `Error::Multiple` are collected one by one.
+    pub fn check<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(Error::Multiple(errors)) => {
+                self.errors.extend(errors);
+                None
+            }
+            Err(err) => {
+                self.errors.push(err);
+                None
+            }
+        }
+    }
+
+    /// Returns the collected errors.
+    #[inline]
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Returns `true` if no errors were collected.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Finishes the collection.
+    ///
+    /// # Returns
+    /// * `Ok(())` if no errors were collected.
+    /// * `Err(Error::Multiple)` with all collected errors in order.
+    pub fn finish(self) -> Result<()> {
+        match self.errors.is_empty() {
+            true => Ok(()),
+            false => Err(Error::Multiple(self.errors)),
+        }
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envs::Envs;
+
+    #[test]
+    fn test_collector() {
+        let envs = Envs::from_map([("COLLECT_PORT", "not_a_number"), ("COLLECT_NAME", "name")]);
+        let mut collector = Collector::new();
+
+        let port = collector.check(envs.get_parse::<u16>("COLLECT_PORT"));
+        let name = collector.check(envs.get("COLLECT_NAME"));
+        let url = collector.check(envs.get("COLLECT_URL"));
+
+        assert_eq!(port, None);
+        assert_eq!(name.as_deref(), Some("name"));
+        assert_eq!(url, None);
+
+        let names: Vec<_> = collector.errors().iter().filter_map(Error::name).collect();
+        assert_eq!(names, ["COLLECT_PORT", "COLLECT_URL"]);
+
+        let err = collector.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration, 2 errors:\n  - `COLLECT_PORT` has a wrong format\n  - `COLLECT_URL` is not set"
+        );
+    }
+
+    #[test]
+    fn test_collector_empty() {
+        let mut collector = Collector::new();
+
+        assert_eq!(collector.check(Ok(1)), Some(1));
+        assert!(collector.is_empty());
+        assert!(collector.finish().is_ok());
+    }
+}
+
+// endregion: --- Tests
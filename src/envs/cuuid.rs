@@ -0,0 +1,61 @@
+use super::{Envs, Error, Result};
+use crate::cuuid::CUuid;
+
+impl Envs {
+    /// Gets the `CUuid` scheme by its name from an environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable, its value is e.g. `b58`, `b64` or `b32`.
+    ///
+    /// # Returns
+    /// The scheme, or `Error::WrongScheme` if the name is unknown or its feature is disabled.
+    pub fn get_cuuid(&self, name: impl AsRef<str>) -> Result<CUuid> {
+        let name = name.as_ref();
+        self.get(name)?
+            .parse()
+            .map_err(|err| Error::WrongScheme(self.full_name(name), err))
+    }
+}
+
+/// Gets the `CUuid` scheme by its name from an environment variable.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable, its value is e.g. `b58`, `b64` or `b32`.
+///
+/// # Returns
+/// The scheme, or `Error::WrongScheme` if the name is unknown or its feature is disabled.
+#[inline]
+pub fn get_cuuid(name: impl AsRef<str>) -> Result<CUuid> {
+    Envs::global().get_cuuid(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_cuuid() {
+        let expected = CUuid::available()[0];
+
+        // Зададим переменные окружения в памяти для теста
+        let envs = Envs::from_map([("TEST_CUUID", expected.to_string())]);
+
+        let result = envs.get_cuuid("TEST_CUUID");
+        assert_eq!(result.unwrap(), expected);
+
+        // Проверяем отсутствие переменной окружения
+        let result = envs.get_cuuid("NON_EXISTENT_CUUID");
+        assert!(matches!(result, Err(Error::MissingEnv(_))));
+    }
+
+    #[test]
+    fn test_get_cuuid_invalid() {
+        let envs = Envs::from_map([("TEST_CUUID_INVALID", "b16")]);
+
+        let result = envs.get_cuuid("TEST_CUUID_INVALID");
+        assert!(matches!(
+            result,
+            Err(Error::WrongScheme(name, _)) if name == "TEST_CUUID_INVALID"
+        ));
+    }
+}
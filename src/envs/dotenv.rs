@@ -0,0 +1,387 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::RwLock;
+
+use super::{Error, Result};
+
+/// Name used in errors of content parsed without a file.
+const INPUT_NAME: &str = "<input>";
+
+/// Variables installed as a fallback of `envs::get`.
+static INSTALLED: RwLock<Option<DotEnv>> = RwLock::new(None);
+
+/// Variables parsed from a `.env` file.
+///
+/// Supports comments, `export` prefix, single and double quotes,
+/// escapes in double quotes and quoted values spanning several lines.
+/// If a variable is defined several times, the last value wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DotEnv {
+    vars: Vec<(String, String)>,
+}
+
+impl DotEnv {
+    /// Loads the `.env` file from the current directory.
+    #[inline]
+    pub fn load() -> Result<DotEnv> {
+        DotEnv::from_path(".env")
+    }
+
+    /// Loads a `.env` file from the path.
+    ///
+    /// # Returns
+    /// * `Err(Error::FileRead)` if the file can not be read.
+    /// * `Err(Error::FileParse)` with the file and line number if the content is invalid.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<DotEnv> {
+        let file = path.as_ref().display().to_string();
+        let content =
+            fs::read_to_string(&path).map_err(|err| Error::FileRead(file.clone(), err))?;
+
+        parse(&content, &file)
+    }
+
+    /// Parses the content of a `.env` file.
+    #[inline]
+    pub fn parse(content: &str) -> Result<DotEnv> {
+        parse(content, INPUT_NAME)
+    }
+
+    /// Returns the value of a variable.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.vars
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns all variables in the order of the file.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.vars
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the number of variables.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vars.len()
+    }
+
+    /// Returns `true` if there are no variables.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty()
+    }
+
+    /// Sets the variables in the process environment, keeping already set ones.
+    pub fn apply(&self) {
+        self.apply_with(
+            |key| env::var_os(key).is_some(),
+            |key, value| env::set_var(key, value),
+        );
+    }
+
+    /// Sets the variables in the process environment, replacing already set ones.
+    pub fn apply_override(&self) {
+        self.apply_with(|_| false, |key, value| env::set_var(key, value));
+    }
+
+    /// Sets the variables with `set`, skipping the ones `is_set` reports as already set.
+    fn apply_with(&self, is_set: impl Fn(&str) -> bool, mut set: impl FnMut(&str, &str)) {
+        for (key, value) in self.iter() {
+            if !is_set(key) {
+                set(key, value);
+            }
+        }
+    }
+
+    /// Makes `envs::get` and all other getters read the variables
+    /// missing in the process environment from this file.
+    ///
+    /// Replaces a previously installed file.
+    pub fn install(self) {
+        if let Ok(mut installed) = INSTALLED.write() {
+            *installed = Some(self);
+        }
+    }
+}
+
+/// Removes the file installed with `DotEnv::install`.
+pub fn uninstall() {
+    if let Ok(mut installed) = INSTALLED.write() {
+        *installed = None;
+    }
+}
+
+/// Returns the value of a variable from the installed file.
+pub(super) fn lookup(name: &str) -> Option<String> {
+    let installed = INSTALLED.read().ok()?;
+    installed.as_ref()?.get(name).map(str::to_string)
+}
+
+// region:    --- Parsing
+
+fn parse(content: &str, file: &str) -> Result<DotEnv> {
+    let error = |line: usize, reason: &'static str| Error::FileParse {
+        file: file.to_string(),
+        line,
+        reason,
+    };
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut dotenv = DotEnv::default();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line_number = index + 1;
+        let line = lines[index].trim();
+        index += 1;
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line
+            .strip_prefix("export ")
+            .map(str::trim_start)
+            .unwrap_or(line);
+
+        let (name, rest) = line
+            .split_once('=')
+            .ok_or_else(|| error(line_number, "expected `=`"))?;
+
+        let name = name.trim();
+        if !is_valid_name(name) {
+            return Err(error(line_number, "invalid variable name"));
+        }
+
+        let raw = rest;
+        let rest = rest.trim_start();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let mut text = rest[1..].to_string();
+
+                // Append next lines until the closing quote
+                let (value, tail) = loop {
+                    if let Some(closed) = close_quote(&text, quote) {
+                        break closed;
+                    }
+
+                    let next = lines
+                        .get(index)
+                        .ok_or_else(|| error(line_number, "unterminated quoted value"))?;
+                    text.push('\n');
+                    text.push_str(next);
+                    index += 1;
+                };
+
+                let tail = tail.trim_start();
+                if !tail.is_empty() && !tail.starts_with('#') {
+                    return Err(error(index, "unexpected characters after quoted value"));
+                }
+
+                value
+            }
+            _ => strip_comment(raw, &['#']).trim().to_string(),
+        };
+
+        match dotenv.vars.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value,
+            None => dotenv.vars.push((name.to_string(), value)),
+        }
+    }
+
+    Ok(dotenv)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Finds the closing quote, returns the unescaped value and the text after the quote.
+pub(super) fn close_quote(text: &str, quote: char) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c == quote => return Some((value, &text[i + 1..])),
+            // Only double quotes support escapes
+            '\\' if quote == '"' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, c @ ('\\' | '"' | '$'))) => value.push(c),
+                Some((_, c)) => {
+                    value.push('\\');
+                    value.push(c);
+                }
+                None => value.push('\\'),
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+/// Removes an inline comment, which starts with one of `markers` after whitespace.
+pub(super) fn strip_comment<'a>(value: &'a str, markers: &[char]) -> &'a str {
+    let mut previous = ' ';
+
+    for (i, c) in value.char_indices() {
+        if markers.contains(&c) && previous.is_whitespace() && i > 0 {
+            return &value[..i];
+        }
+        previous = c;
+    }
+
+    value
+}
+
+// endregion: --- Parsing
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::envs::{Envs, Layer, Layered};
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let dotenv = DotEnv::parse(
+            r#"
+# Comment
+PLAIN=value # inline comment
+COMMENT= # only comment
+export EXPORTED = exported
+EMPTY=
+HASH=#not a comment
+SINGLE='single \n # quoted'
+DOUBLE="double \"quoted\"\t\n" # comment
+"#,
+        )?;
+
+        assert_eq!(dotenv.get("PLAIN"), Some("value"));
+        assert_eq!(dotenv.get("EXPORTED"), Some("exported"));
+        assert_eq!(dotenv.get("EMPTY"), Some(""));
+        assert_eq!(dotenv.get("COMMENT"), Some(""));
+        assert_eq!(dotenv.get("HASH"), Some("#not a comment"));
+        assert_eq!(dotenv.get("SINGLE"), Some("single \\n # quoted"));
+        assert_eq!(dotenv.get("DOUBLE"), Some("double \"quoted\"\t\n"));
+        assert_eq!(dotenv.len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_multiline() -> Result<()> {
+        let dotenv = DotEnv::parse("KEY=\"-----BEGIN-----\nline\n-----END-----\"\nNEXT=1\nNEXT=2")?;
+
+        assert_eq!(
+            dotenv.get("KEY"),
+            Some("-----BEGIN-----\nline\n-----END-----")
+        );
+        // The last value wins
+        assert_eq!(dotenv.get("NEXT"), Some("2"));
+        assert_eq!(dotenv.iter().count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let check = |content: &str, expected_line: usize| {
+            let result = DotEnv::parse(content);
+            assert!(
+                matches!(result, Err(Error::FileParse { line, .. }) if line == expected_line),
+                "{content}"
+            );
+        };
+
+        check("OK=1\nNO_EQUALS", 2);
+        check("OK=1\n\n1INVALID=1", 3);
+        check("OK=1\nQUOTED=\"not closed\nOTHER=1", 2);
+        check("QUOTED='value' tail", 1);
+    }
+
+    #[test]
+    fn test_from_path() -> Result<()> {
+        let path = env::temp_dir().join("grapple_utils_test.env");
+        fs::write(&path, "FILE_VAR=1\nBROKEN")
+            .map_err(|err| Error::FileRead(String::new(), err))?;
+
+        let result = DotEnv::from_path(&path);
+        let _ = fs::remove_file(&path);
+
+        match result {
+            Err(Error::FileParse { file, line, .. }) => {
+                assert_eq!(file, path.display().to_string());
+                assert_eq!(line, 2);
+            }
+            other => panic!("Unexpected result {other:?}"),
+        }
+
+        assert!(matches!(
+            DotEnv::from_path("missing.env"),
+            Err(Error::FileRead(..))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> Result<()> {
+        let dotenv = DotEnv::parse("DOTENV_APPLY_SET=file\nDOTENV_APPLY_NEW=file")?;
+
+        // Окружение в памяти вместо окружения процесса
+        let mut vars = HashMap::from([("DOTENV_APPLY_SET".to_string(), "process".to_string())]);
+
+        dotenv.apply_with(
+            |key| key == "DOTENV_APPLY_SET",
+            |key, value| {
+                vars.insert(key.into(), value.into());
+            },
+        );
+        assert_eq!(vars["DOTENV_APPLY_SET"], "process");
+        assert_eq!(vars["DOTENV_APPLY_NEW"], "file");
+
+        dotenv.apply_with(
+            |_| false,
+            |key, value| {
+                vars.insert(key.into(), value.into());
+            },
+        );
+        assert_eq!(vars["DOTENV_APPLY_SET"], "file");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dotenv_layer() -> Result<()> {
+        let dotenv = DotEnv::parse("DOTENV_LAYER_SET=file\nDOTENV_LAYER_NEW=file")?;
+        let envs = Envs::from_layered(Layered::new().with_dotenv(dotenv).with(
+            Layer::Process,
+            HashMap::from([("DOTENV_LAYER_SET".to_string(), "process".to_string())]),
+        ));
+
+        // Process environment has priority over the `.env` file
+        assert_eq!(envs.get("DOTENV_LAYER_SET")?, "process");
+        assert_eq!(
+            envs.get_with_layer("DOTENV_LAYER_NEW")?,
+            ("file".to_string(), Layer::DotEnv)
+        );
+        assert!(envs.get("DOTENV_LAYER_MISSING").is_err());
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
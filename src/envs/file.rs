@@ -0,0 +1,204 @@
+use std::fs;
+use std::path::Path;
+
+use super::dotenv::{close_quote, strip_comment};
+use super::{Error, Result};
+
+/// Name used in errors of content parsed without a file.
+const INPUT_NAME: &str = "<input>";
+
+/// Variables read from a TOML or INI configuration file.
+///
+/// Supports the flat subset shared by both formats: `[section]` headers,
+/// `key = value` pairs with quoted or bare values and `#` or `;` comments.
+/// Keys are turned into variable names by joining the section and the key
+/// with `_` in uppercase, e.g. `url` in `[database]` becomes `DATABASE_URL`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigFile {
+    vars: Vec<(String, String)>,
+}
+
+impl ConfigFile {
+    /// Loads a configuration file from the path.
+    ///
+    /// # Returns
+    /// * `Err(Error::FileRead)` if the file can not be read.
+    /// * `Err(Error::FileParse)` with the file and line number if the content is invalid.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<ConfigFile> {
+        let file = path.as_ref().display().to_string();
+        let content =
+            fs::read_to_string(&path).map_err(|err| Error::FileRead(file.clone(), err))?;
+
+        parse(&content, &file)
+    }
+
+    /// Parses the content of a configuration file.
+    #[inline]
+    pub fn parse(content: &str) -> Result<ConfigFile> {
+        parse(content, INPUT_NAME)
+    }
+
+    /// Returns the value of a variable.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.vars
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns all variables in the order of the file.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.vars
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+fn parse(content: &str, file: &str) -> Result<ConfigFile> {
+    let error = |line: usize, reason: &'static str| Error::FileParse {
+        file: file.to_string(),
+        line,
+        reason,
+    };
+
+    let mut config = ConfigFile::default();
+    let mut section = String::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            if header.starts_with('[') {
+                return Err(error(line_number, "arrays of tables are not supported"));
+            }
+
+            let header = strip_comment(header, &['#', ';']).trim_end();
+            section = header
+                .strip_suffix(']')
+                .ok_or_else(|| error(line_number, "expected `]`"))?
+                .trim()
+                .to_string();
+            continue;
+        }
+
+        let (key, raw) = line
+            .split_once('=')
+            .ok_or_else(|| error(line_number, "expected `=`"))?;
+
+        let key = key.trim().trim_matches('"');
+        if key.is_empty() {
+            return Err(error(line_number, "empty key"));
+        }
+
+        let rest = raw.trim_start();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let (value, tail) = close_quote(&rest[1..], quote)
+                    .ok_or_else(|| error(line_number, "unterminated quoted value"))?;
+
+                let tail = tail.trim_start();
+                if !tail.is_empty() && !tail.starts_with('#') && !tail.starts_with(';') {
+                    return Err(error(
+                        line_number,
+                        "unexpected characters after quoted value",
+                    ));
+                }
+
+                value
+            }
+            _ => strip_comment(raw, &['#', ';']).trim().to_string(),
+        };
+
+        let name = var_name(&section, key);
+        match config
+            .vars
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = value,
+            None => config.vars.push((name, value)),
+        }
+    }
+
+    Ok(config)
+}
+
+/// Joins the section and the key into an uppercase variable name.
+fn var_name(section: &str, key: &str) -> String {
+    let name = match section.is_empty() {
+        true => key.to_string(),
+        false => format!("{section}_{key}"),
+    };
+
+    name.chars()
+        .map(|c| match c {
+            '.' | '-' | ' ' => '_',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toml() -> Result<()> {
+        let config = ConfigFile::parse(
+            r#"
+# Comment
+name = "service" # comment
+port = 8080
+
+[database.primary]
+url = 'postgres://localhost'
+pool-size = 5
+"#,
+        )?;
+
+        assert_eq!(config.get("NAME"), Some("service"));
+        assert_eq!(config.get("PORT"), Some("8080"));
+        assert_eq!(
+            config.get("DATABASE_PRIMARY_URL"),
+            Some("postgres://localhost")
+        );
+        assert_eq!(config.get("DATABASE_PRIMARY_POOL_SIZE"), Some("5"));
+        assert_eq!(config.iter().count(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ini() -> Result<()> {
+        let config = ConfigFile::parse("; Comment\n[server]\nhost = localhost ; comment\n")?;
+
+        assert_eq!(config.get("SERVER_HOST"), Some("localhost"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let check = |content: &str, expected_line: usize| {
+            let result = ConfigFile::parse(content);
+            assert!(
+                matches!(result, Err(Error::FileParse { line, .. }) if line == expected_line),
+                "{content}"
+            );
+        };
+
+        check("a = 1\nno_equals", 2);
+        check("[section\na = 1", 1);
+        check("[[array]]", 1);
+        check("a = \"not closed", 1);
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,293 @@
+use super::{Error, Result};
+
+/// Grammar of key-value pairs read by `get_keys` and other keys getters.
+///
+/// Pairs are separated by `,` and keys by `:` by default, e.g. `KEY1:VALUE1,KEY2:VALUE2`.
+/// The key ends at the first key separator, so values may contain it: `API:https://host:8443`.
+/// Separators are literal inside double quotes or after `\`, e.g. `GREETING:"Hello, world"`
+/// or `GREETING:Hello\, world`. Whitespace around unquoted keys and values is trimmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeysFormat {
+    pair_separator: char,
+    key_separator: char,
+    skip_empty: bool,
+    allow_duplicates: bool,
+}
+
+impl Default for KeysFormat {
+    fn default() -> KeysFormat {
+        KeysFormat {
+            pair_separator: ',',
+            key_separator: ':',
+            skip_empty: false,
+            allow_duplicates: false,
+        }
+    }
+}
+
+impl KeysFormat {
+    /// Creates the default format, `KEY1:VALUE1,KEY2:VALUE2`.
+    #[inline]
+    pub fn new() -> KeysFormat {
+        KeysFormat::default()
+    }
+
+    /// Sets the separator of pairs, `,` by default.
+    #[inline]
+    pub fn pair_separator(mut self, separator: char) -> KeysFormat {
+        self.pair_separator = separator;
+        self
+    }
+
+    /// Sets the separator of a key and its value, `:` by default.
+    #[inline]
+    pub fn key_separator(mut self, separator: char) -> KeysFormat {
+        self.key_separator = separator;
+        self
+    }
+
+    /// Skips empty pairs, e.g. of a trailing separator, instead of failing.
+    #[inline]
+    pub fn skip_empty(mut self, skip: bool) -> KeysFormat {
+        self.skip_empty = skip;
+        self
+    }
+
+    /// Allows keys defined several times, the last value wins, instead of failing.
+    #[inline]
+    pub fn allow_duplicates(mut self, allow: bool) -> KeysFormat {
+        self.allow_duplicates = allow;
+        self
+    }
+
+    /// Returns `true` if keys may be defined several times.
+    #[inline]
+    pub(super) fn allows_duplicates(&self) -> bool {
+        self.allow_duplicates
+    }
+
+    /// Splits the value of the variable into pairs with their indexes in the value.
+    ///
+    /// # Returns
+    /// * `Err(Error::WrongPair)` with the index of a malformed pair.
+    /// * `Err(Error::DuplicateKey)` if a key is defined several times.
+    pub(super) fn split(&self, name: &str, value: &str) -> Result<Vec<(usize, String, String)>> {
+        let error = |index: usize, reason: &'static str| Error::WrongPair {
+            name: name.to_string(),
+            index,
+            reason,
+        };
+
+        let entries = split_entries(value, self.pair_separator, Some(self.key_separator))
+            .map_err(|(index, reason)| error(index, reason))?;
+        let mut pairs: Vec<(usize, String, String)> = Vec::new();
+
+        for (index, (key, value)) in entries.into_iter().enumerate() {
+            match key {
+                None if value.is_empty() && self.skip_empty => {}
+                None if value.is_empty() => return Err(error(index, "empty pair")),
+                None => return Err(error(index, "missing key separator")),
+                Some(key) if key.is_empty() => return Err(error(index, "empty key")),
+                Some(key) => match pairs.iter_mut().find(|(_, k, _)| *k == key) {
+                    Some(_) if !self.allow_duplicates => {
+                        return Err(Error::DuplicateKey {
+                            name: name.to_string(),
+                            key,
+                            index,
+                        })
+                    }
+                    Some(pair) => *pair = (index, key, value),
+                    None => pairs.push((index, key, value)),
+                },
+            }
+        }
+
+        Ok(pairs)
+    }
+}
+
+/// Entry of a value with its key if there is a key separator.
+type Entry = (Option<String>, String);
+
+/// Splits the value into entries by the separator, with keys if the key separator is set.
+///
+/// # Returns
+/// Entries in order, or the index of the malformed entry with the reason.
+pub(super) fn split_entries(
+    value: &str,
+    separator: char,
+    key_separator: Option<char>,
+) -> core::result::Result<Vec<Entry>, (usize, &'static str)> {
+    let mut entries = Vec::new();
+    let mut key: Option<String> = None;
+    let mut part = Part::default();
+    let mut quoted = false;
+    let mut chars = value.chars();
+
+    loop {
+        match chars.next() {
+            Some('\\') => match chars.next() {
+                Some(c) => part.push_literal(c),
+                None => return Err((entries.len(), "escape at the end of the value")),
+            },
+            Some('"') => {
+                quoted = !quoted;
+                part.mark_literal();
+            }
+            Some(c) if quoted => part.push_literal(c),
+            Some(c) if Some(c) == key_separator && key.is_none() => {
+                key = Some(part.take());
+            }
+            Some(c) if c != separator => part.push(c),
+            end => {
+                if end.is_none() && quoted {
+                    return Err((entries.len(), "unterminated quote"));
+                }
+
+                entries.push((key.take(), part.take()));
+
+                if end.is_none() {
+                    return Ok(entries);
+                }
+            }
+        }
+    }
+}
+
+/// Key or value being read, whitespace is trimmed around its unquoted part.
+#[derive(Default)]
+struct Part {
+    text: String,
+    /// Range of quoted and escaped characters, they are never trimmed.
+    literal: Option<(usize, usize)>,
+}
+
+impl Part {
+    fn push(&mut self, c: char) {
+        self.text.push(c);
+    }
+
+    fn push_literal(&mut self, c: char) {
+        self.mark_literal();
+        self.text.push(c);
+        if let Some((_, end)) = &mut self.literal {
+            *end = self.text.len();
+        }
+    }
+
+    /// Marks the current position as literal, e.g. for empty quotes.
+    fn mark_literal(&mut self) {
+        let len = self.text.len();
+        let (start, _) = self.literal.get_or_insert((len, len));
+        *start = (*start).min(len);
+    }
+
+    fn take(&mut self) -> String {
+        let text = core::mem::take(&mut self.text);
+        let mut start = text.len() - text.trim_start().len();
+        let mut end = text.trim_end().len();
+
+        if let Some((literal_start, literal_end)) = self.literal.take() {
+            start = start.min(literal_start);
+            end = end.max(literal_end);
+        }
+
+        match start < end {
+            true => text[start..end].to_string(),
+            false => String::new(),
+        }
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(format: KeysFormat, value: &str) -> Result<Vec<(String, String)>> {
+        let pairs = format.split("KEYS", value)?;
+        Ok(pairs
+            .into_iter()
+            .map(|(_, key, value)| (key, value))
+            .collect())
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split() -> Result<()> {
+        let format = KeysFormat::new();
+
+        assert_eq!(
+            split(format, " KEY1 : VALUE1 , KEY2:VALUE2")?,
+            pairs(&[("KEY1", "VALUE1"), ("KEY2", "VALUE2")])
+        );
+        // The key ends at the first separator
+        assert_eq!(
+            split(format, "API:https://host:8443")?,
+            pairs(&[("API", "https://host:8443")])
+        );
+        // Quotes and escapes
+        assert_eq!(
+            split(format, r#"A:"Hello, world",B:a\,b\:c,C:" padded ",D:"""#)?,
+            pairs(&[
+                ("A", "Hello, world"),
+                ("B", "a,b:c"),
+                ("C", " padded "),
+                ("D", "")
+            ])
+        );
+        assert_eq!(split(format, "EMPTY:")?, pairs(&[("EMPTY", "")]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_format() -> Result<()> {
+        let format = KeysFormat::new()
+            .pair_separator(';')
+            .key_separator('=')
+            .skip_empty(true)
+            .allow_duplicates(true);
+
+        assert_eq!(
+            split(format, "A=1;;B=x:y,z;A=2;")?,
+            pairs(&[("A", "2"), ("B", "x:y,z")])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_errors() {
+        let check = |value: &str, expected_index: usize, expected_reason: &str| {
+            let result = KeysFormat::new().split("KEYS", value);
+            assert!(
+                matches!(&result, Err(Error::WrongPair { name, index, reason })
+                    if name == "KEYS" && *index == expected_index && *reason == expected_reason),
+                "{value}: {result:?}"
+            );
+        };
+
+        check("A:1,B", 1, "missing key separator");
+        check("A:1,,B:2", 1, "empty pair");
+        check("A:1,", 1, "empty pair");
+        check("A:1,:2", 1, "empty key");
+        check("A:1,B:\"2,C:3", 1, "unterminated quote");
+        check("A:1\\", 0, "escape at the end of the value");
+
+        let result = KeysFormat::new().split("KEYS", "A:1,B:2,A:3");
+        assert!(matches!(
+            result,
+            Err(Error::DuplicateKey { key, index: 2, .. }) if key == "A"
+        ));
+    }
+}
+
+// endregion: --- Tests
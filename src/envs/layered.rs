@@ -0,0 +1,249 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::sync::RwLock;
+
+use super::dotenv::{self, DotEnv};
+use super::file::ConfigFile;
+
+/// Resolver installed for all getters.
+static INSTALLED: RwLock<Option<Layered>> = RwLock::new(None);
+
+/// Source of environment variables.
+pub trait EnvSource: core::fmt::Debug + Send + Sync {
+    /// Returns the value of a variable, `None` if the source does not have it.
+    fn get(&self, name: &str) -> Option<String>;
+}
+
+/// Environment of the current process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessEnv;
+
+impl EnvSource for ProcessEnv {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        env::var(name).ok()
+    }
+}
+
+impl EnvSource for DotEnv {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        DotEnv::get(self, name).map(str::to_string)
+    }
+}
+
+impl EnvSource for ConfigFile {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        ConfigFile::get(self, name).map(str::to_string)
+    }
+}
+
+impl EnvSource for HashMap<String, String> {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        HashMap::get(self, name).cloned()
+    }
+}
+
+impl EnvSource for BTreeMap<String, String> {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        BTreeMap::get(self, name).cloned()
+    }
+}
+
+/// Layer of a `Layered` resolver, ordered from the lowest to the highest precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    Default,
+    DotEnv,
+    File,
+    Process,
+    Override,
+}
+
+/// Resolver combining several sources by the precedence of their layers.
+///
+/// Defaults < `.env` files < configuration file < process environment < overrides.
+/// Within the same layer the source added later wins.
+#[derive(Debug, Default)]
+pub struct Layered {
+    sources: Vec<(Layer, Box<dyn EnvSource>)>,
+}
+
+impl Layered {
+    /// Creates a resolver without sources.
+    #[inline]
+    pub fn new() -> Layered {
+        Layered::default()
+    }
+
+    /// Adds a source to the layer.
+    pub fn with(mut self, layer: Layer, source: impl EnvSource + 'static) -> Layered {
+        self.sources.push((layer, Box::new(source)));
+        // Stable sort keeps the order of sources within a layer
+        self.sources.sort_by_key(|(layer, _)| *layer);
+        self
+    }
+
+    /// Adds default values.
+    pub fn with_defaults<K, V>(self, defaults: impl IntoIterator<Item = (K, V)>) -> Layered
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.with(Layer::Default, into_map(defaults))
+    }
+
+    /// Adds variables of a `.env` file.
+    #[inline]
+    pub fn with_dotenv(self, dotenv: DotEnv) -> Layered {
+        self.with(Layer::DotEnv, dotenv)
+    }
+
+    /// Adds variables of a TOML or INI configuration file.
+    #[inline]
+    pub fn with_file(self, file: ConfigFile) -> Layered {
+        self.with(Layer::File, file)
+    }
+
+    /// Adds the environment of the current process.
+    #[inline]
+    pub fn with_process_env(self) -> Layered {
+        self.with(Layer::Process, ProcessEnv)
+    }
+
+    /// Adds values overriding all other layers.
+    pub fn with_overrides<K, V>(self, overrides: impl IntoIterator<Item = (K, V)>) -> Layered
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.with(Layer::Override, into_map(overrides))
+    }
+
+    /// Returns the value of a variable and the layer that supplied it.
+    pub fn resolve(&self, name: &str) -> Option<(String, Layer)> {
+        self.sources
+            .iter()
+            .rev()
+            .find_map(|(layer, source)| source.get(name).map(|value| (value, *layer)))
+    }
+
+    /// Returns the layer that supplies the variable.
+    #[inline]
+    pub fn layer_of(&self, name: &str) -> Option<Layer> {
+        self.resolve(name).map(|(_, layer)| layer)
+    }
+
+    /// Makes `envs::get` and all other getters read variables from this resolver
+    /// instead of the process environment.
+    ///
+    /// Replaces a previously installed resolver.
+    pub fn install(self) {
+        if let Ok(mut installed) = INSTALLED.write() {
+            *installed = Some(self);
+        }
+    }
+}
+
+impl EnvSource for Layered {
+    #[inline]
+    fn get(&self, name: &str) -> Option<String> {
+        self.resolve(name).map(|(value, _)| value)
+    }
+}
+
+/// Removes the resolver installed with `Layered::install`.
+pub fn uninstall() {
+    if let Ok(mut installed) = INSTALLED.write() {
+        *installed = None;
+    }
+}
+
+/// Resolves a variable with the installed resolver, or with the process
+/// environment and the installed `.env` file if there is none.
+pub(super) fn resolve(name: &str) -> Option<(String, Layer)> {
+    if let Some(layered) = INSTALLED.read().ok().as_ref().and_then(|i| i.as_ref()) {
+        return layered.resolve(name);
+    }
+
+    env::var(name)
+        .ok()
+        .map(|value| (value, Layer::Process))
+        .or_else(|| dotenv::lookup(name).map(|value| (value, Layer::DotEnv)))
+}
+
+fn into_map<K, V>(values: impl IntoIterator<Item = (K, V)>) -> HashMap<String, String>
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    values
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect()
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() -> crate::envs::Result<()> {
+        // Process layer without changing the environment of the process
+        let layered = Layered::new()
+            .with_overrides([("LAYERED_OVERRIDE", "override")])
+            .with(Layer::Process, into_map([("LAYERED_PROCESS", "process")]))
+            .with_file(ConfigFile::parse(
+                "layered_process = file\nlayered_file = file\nlayered_override = file",
+            )?)
+            .with_dotenv(DotEnv::parse("LAYERED_FILE=dotenv\nLAYERED_DOTENV=dotenv")?)
+            .with_defaults([
+                ("LAYERED_DOTENV", "default"),
+                ("LAYERED_DEFAULT", "default"),
+            ]);
+
+        let check = |name: &str, value: &str, layer: Layer| {
+            assert_eq!(
+                layered.resolve(name),
+                Some((value.to_string(), layer)),
+                "{name}"
+            );
+        };
+
+        check("LAYERED_OVERRIDE", "override", Layer::Override);
+        check("LAYERED_PROCESS", "process", Layer::Process);
+        check("LAYERED_FILE", "file", Layer::File);
+        check("LAYERED_DOTENV", "dotenv", Layer::DotEnv);
+        check("LAYERED_DEFAULT", "default", Layer::Default);
+        assert_eq!(layered.layer_of("LAYERED_MISSING"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_layer() {
+        let layered = Layered::new()
+            .with_defaults([("LAYERED_SAME", "first")])
+            .with_defaults([("LAYERED_SAME", "second")]);
+
+        assert_eq!(layered.get("LAYERED_SAME"), Some("second".to_string()));
+    }
+
+    #[test]
+    fn test_process_env() {
+        // Set by cargo for every test process
+        let layered = Layered::new().with_process_env();
+
+        assert_eq!(
+            layered.resolve("CARGO_PKG_NAME"),
+            Some((env!("CARGO_PKG_NAME").to_string(), Layer::Process))
+        );
+    }
+}
+
+// endregion: --- Tests
@@ -1,6 +1,123 @@
 #[macro_export]
 macro_rules! generate_env_methods {
     ($base:ident) => {
+        paste::paste! {
+        impl Envs {
+            /// Gets the value of an environment variable in a specific encoded format.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable.
+            ///
+            /// # Returns
+            /// A decoded string into UTF-8 string.
+            pub fn [<get_ $base _as_s>](&self, name: impl AsRef<str>) -> Result<String> {
+                let name = name.as_ref();
+                decode_to_string(&self.get(name)?).map_err(|_| Error::WrongFormat(self.full_name(name)))
+            }
+
+            /// Gets the value of an environment variable in a specific encoded format.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable.
+            ///
+            /// # Returns
+            /// A decoded string into a vector of bytes.
+            pub fn [<get_ $base _as_u8s>](&self, name: impl AsRef<str>) -> Result<Vec<u8>> {
+                let name = name.as_ref();
+                decode(&self.get(name)?).map_err(|_| Error::WrongFormat(self.full_name(name)))
+            }
+
+            /// Retrieves a set of key-value pairs from an environment variable,
+            /// decoding the values from a specific format into UTF-8 strings.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable containing the key-value pairs.
+            ///
+            /// # Returns
+            /// A `HashMap<String, String>` representing the key-value pairs extracted
+            /// from the environment variable, with values decoded from the specific format.
+            pub fn [<get_keys_ $base _as_s>](&self, name: impl AsRef<str>) -> Result<HashMap<String, String>> {
+                let name = name.as_ref();
+                let mut hash_map = HashMap::new();
+
+                for (index, key, value) in self.read_keys_with(name, &Default::default())? {
+                    let value = decode_to_string(&value)
+                        .map_err(|_| self.pair_error(name, index, "value can not be decoded"))?;
+                    hash_map.insert(key, value);
+                }
+
+                Ok(hash_map)
+            }
+
+            /// Retrieves a set of key-value pairs from an environment variable,
+            /// decoding the values from a specific format into byte vectors.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable containing the key-value pairs.
+            ///
+            /// # Returns
+            /// A `HashMap<String, Vec<u8>>` representing the key-value pairs extracted
+            /// from the environment variable, with values decoded from the specific format.
+            pub fn [<get_keys_ $base _as_u8s>](&self, name: impl AsRef<str>) -> Result<HashMap<String, Vec<u8>>> {
+                let name = name.as_ref();
+                let mut hash_map = HashMap::new();
+
+                for (index, key, value) in self.read_keys_with(name, &Default::default())? {
+                    let value = decode(&value)
+                        .map_err(|_| self.pair_error(name, index, "value can not be decoded"))?;
+                    hash_map.insert(key, value);
+                }
+
+                Ok(hash_map)
+            }
+
+            /// Gets the value of an optional environment variable in a specific encoded format.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable.
+            ///
+            /// # Returns
+            /// `Ok(None)` if the variable is not set, a decoded UTF-8 string otherwise.
+            pub fn [<get_ $base _as_s_opt>](&self, name: impl AsRef<str>) -> Result<Option<String>> {
+                self.[<get_ $base _as_s>](name).map(Some).or_else(Error::missing_as_none)
+            }
+
+            /// Gets the value of an optional environment variable in a specific encoded format.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable.
+            ///
+            /// # Returns
+            /// `Ok(None)` if the variable is not set, a decoded vector of bytes otherwise.
+            pub fn [<get_ $base _as_u8s_opt>](&self, name: impl AsRef<str>) -> Result<Option<Vec<u8>>> {
+                self.[<get_ $base _as_u8s>](name).map(Some).or_else(Error::missing_as_none)
+            }
+
+            /// Retrieves an optional set of key-value pairs from an environment variable,
+            /// decoding the values from a specific format into UTF-8 strings.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable containing the key-value pairs.
+            ///
+            /// # Returns
+            /// `Ok(None)` if the variable is not set, decoded key-value pairs otherwise.
+            pub fn [<get_keys_ $base _as_s_opt>](&self, name: impl AsRef<str>) -> Result<Option<HashMap<String, String>>> {
+                self.[<get_keys_ $base _as_s>](name).map(Some).or_else(Error::missing_as_none)
+            }
+
+            /// Retrieves an optional set of key-value pairs from an environment variable,
+            /// decoding the values from a specific format into byte vectors.
+            ///
+            /// # Parameters
+            /// - `name`: The name of the environment variable containing the key-value pairs.
+            ///
+            /// # Returns
+            /// `Ok(None)` if the variable is not set, decoded key-value pairs otherwise.
+            pub fn [<get_keys_ $base _as_u8s_opt>](&self, name: impl AsRef<str>) -> Result<Option<HashMap<String, Vec<u8>>>> {
+                self.[<get_keys_ $base _as_u8s>](name).map(Some).or_else(Error::missing_as_none)
+            }
+        }}
+
         paste::paste! {
         /// Gets the value of an environment variable in a specific encoded format.
         ///
@@ -9,8 +126,9 @@ macro_rules! generate_env_methods {
         ///
         /// # Returns
         /// A decoded string into UTF-8 string.
-        pub fn [<get_ $base _as_s>] (name: &'static str) -> Result<String>  {
-            decode_to_string(&get(name)?).map_err(|_| Error::WrongFormat(name))
+        #[inline]
+        pub fn [<get_ $base _as_s>] (name: impl AsRef<str>) -> Result<String>  {
+            Envs::global().[<get_ $base _as_s>](name)
         }}
 
         paste::paste! {
@@ -21,8 +139,9 @@ macro_rules! generate_env_methods {
         ///
         /// # Returns
         /// A decoded string into a vector of bytes.
-        pub fn [<get_ $base _as_u8s>](name: &'static str) -> Result<Vec<u8>> {
-            decode(&get(name)?).map_err(|_| Error::WrongFormat(name))
+        #[inline]
+        pub fn [<get_ $base _as_u8s>](name: impl AsRef<str>) -> Result<Vec<u8>> {
+            Envs::global().[<get_ $base _as_u8s>](name)
         }}
 
         paste::paste! {
@@ -40,15 +159,9 @@ macro_rules! generate_env_methods {
         /// from the environment variable, with values decoded from the specific format.
         /// If the environment variable is not set, if the format is incorrect, or if
         /// the values cannot be decoded, an error is returned.
-        pub fn [<get_keys_ $base _as_s>](name: &'static str) -> Result<HashMap<String, String>> {
-            let mut hash_map = HashMap::new();
-
-            for (key, value) in read_keys(name)? {
-                let value = decode_to_string(&value).map_err(|_| Error::WrongFormat(name))?;
-                hash_map.insert(key, value);
-            }
-
-            Ok(hash_map)
+        #[inline]
+        pub fn [<get_keys_ $base _as_s>](name: impl AsRef<str>) -> Result<HashMap<String, String>> {
+            Envs::global().[<get_keys_ $base _as_s>](name)
         }}
 
         paste::paste! {
@@ -66,15 +179,63 @@ macro_rules! generate_env_methods {
         /// from the environment variable, with values decoded from the specific format.
         /// If the environment variable is not set, if the format is incorrect, or if
         /// the values cannot be decoded, an error is returned.
-        pub fn [<get_keys_ $base _as_u8s>](name: &'static str) -> Result<HashMap<String, Vec<u8>>> {
-            let mut hash_map = HashMap::new();
+        #[inline]
+        pub fn [<get_keys_ $base _as_u8s>](name: impl AsRef<str>) -> Result<HashMap<String, Vec<u8>>> {
+            Envs::global().[<get_keys_ $base _as_u8s>](name)
+        }}
 
-            for (key, value) in read_keys(name)? {
-                let value = decode(&value).map_err(|_| Error::WrongFormat(name))?;
-                hash_map.insert(key, value);
-            }
+        paste::paste! {
+        /// Gets the value of an optional environment variable in a specific encoded format.
+        ///
+        /// # Parameters
+        /// - `name`: The name of the environment variable.
+        ///
+        /// # Returns
+        /// `Ok(None)` if the variable is not set, a decoded UTF-8 string otherwise.
+        #[inline]
+        pub fn [<get_ $base _as_s_opt>](name: impl AsRef<str>) -> Result<Option<String>> {
+            Envs::global().[<get_ $base _as_s_opt>](name)
+        }}
 
-            Ok(hash_map)
+        paste::paste! {
+        /// Gets the value of an optional environment variable in a specific encoded format.
+        ///
+        /// # Parameters
+        /// - `name`: The name of the environment variable.
+        ///
+        /// # Returns
+        /// `Ok(None)` if the variable is not set, a decoded vector of bytes otherwise.
+        #[inline]
+        pub fn [<get_ $base _as_u8s_opt>](name: impl AsRef<str>) -> Result<Option<Vec<u8>>> {
+            Envs::global().[<get_ $base _as_u8s_opt>](name)
+        }}
+
+        paste::paste! {
+        /// Retrieves an optional set of key-value pairs from an environment variable,
+        /// decoding the values from a specific format into UTF-8 strings.
+        ///
+        /// # Parameters
+        /// - `name`: The name of the environment variable containing the key-value pairs.
+        ///
+        /// # Returns
+        /// `Ok(None)` if the variable is not set, decoded key-value pairs otherwise.
+        #[inline]
+        pub fn [<get_keys_ $base _as_s_opt>](name: impl AsRef<str>) -> Result<Option<HashMap<String, String>>> {
+            Envs::global().[<get_keys_ $base _as_s_opt>](name)
+        }}
+
+        paste::paste! {
+        /// Retrieves an optional set of key-value pairs from an environment variable,
+        /// decoding the values from a specific format into byte vectors.
+        ///
+        /// # Parameters
+        /// - `name`: The name of the environment variable containing the key-value pairs.
+        ///
+        /// # Returns
+        /// `Ok(None)` if the variable is not set, decoded key-value pairs otherwise.
+        #[inline]
+        pub fn [<get_keys_ $base _as_u8s_opt>](name: impl AsRef<str>) -> Result<Option<HashMap<String, Vec<u8>>>> {
+            Envs::global().[<get_keys_ $base _as_u8s_opt>](name)
         }}
     };
 }
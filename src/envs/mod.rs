@@ -1,15 +1,29 @@
 use std::collections::HashMap;
-use std::env;
 use std::str::FromStr;
+use std::sync::Arc;
 
 mod macros;
 
+mod collections;
+mod collector;
+pub mod dotenv;
+pub mod file;
+mod keys;
+pub mod layered;
+mod optional;
+mod scope;
+
 #[cfg(feature = "b32")]
 mod b32;
 #[cfg(feature = "b58")]
 mod b58;
 #[cfg(feature = "b64")]
 mod b64u;
+#[cfg(all(
+    feature = "cuuid",
+    any(feature = "b58", feature = "b64", feature = "b32")
+))]
+mod cuuid;
 
 #[cfg(feature = "b32")]
 pub use b32::*;
@@ -17,65 +31,286 @@ pub use b32::*;
 pub use b58::*;
 #[cfg(feature = "b64")]
 pub use b64u::*;
+#[cfg(all(
+    feature = "cuuid",
+    any(feature = "b58", feature = "b64", feature = "b32")
+))]
+pub use cuuid::*;
+
+#[cfg(feature = "derive")]
+pub use grapple_utils_derive::FromEnv;
+
+pub use collections::*;
+pub use collector::Collector;
+pub use keys::KeysFormat;
+pub use layered::{EnvSource, Layer, Layered};
+pub use optional::*;
+pub use scope::Scope;
+
+/// Reader of environment variables from the global environment or an injected source.
+///
+/// Every getter of the module, including the encoded ones, is available as a method,
+/// so configuration loading can be tested with `Envs::from_map` without touching
+/// the process environment.
+#[derive(Debug, Clone, Default)]
+pub struct Envs {
+    /// `None` reads the global environment.
+    source: Option<Arc<Layered>>,
+    /// Prefix of all variable names, see `Scope`.
+    prefix: String,
+}
+
+impl Envs {
+    /// Creates a reader of the global environment, used by the module functions.
+    #[inline]
+    pub fn global() -> Envs {
+        Envs::default()
+    }
+
+    /// Creates a reader of variables from the map, they are reported as `Layer::Override`.
+    pub fn from_map<K, V>(vars: impl IntoIterator<Item = (K, V)>) -> Envs
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Envs::from_layered(Layered::new().with_overrides(vars))
+    }
+
+    /// Creates a reader of the layered sources.
+    #[inline]
+    pub fn from_layered(layered: Layered) -> Envs {
+        Envs {
+            source: Some(Arc::new(layered)),
+            prefix: String::new(),
+        }
+    }
+
+    /// Creates a scope reading variables with the prefix appended to the current one.
+    pub fn scope(&self, prefix: impl AsRef<str>) -> Scope {
+        Scope::from_envs(Envs {
+            source: self.source.clone(),
+            prefix: self.full_name(prefix),
+        })
+    }
+
+    /// Returns the full name of a variable with the prefix of the reader.
+    #[inline]
+    pub fn full_name(&self, name: impl AsRef<str>) -> String {
+        format!("{}{}", self.prefix, name.as_ref())
+    }
+
+    /// Gets the value of an environment variable into a string.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    ///
+    /// # Returns
+    /// A string representing the value of the environment variable.
+    #[inline]
+    pub fn get(&self, name: impl AsRef<str>) -> Result<String> {
+        self.get_with_layer(name).map(|(value, _)| value)
+    }
+
+    /// Gets the value of an environment variable and the layer that supplied it.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    ///
+    /// # Returns
+    /// The value of the environment variable and its `Layer`.
+    pub fn get_with_layer(&self, name: impl AsRef<str>) -> Result<(String, Layer)> {
+        let name = self.full_name(name);
+
+        match &self.source {
+            Some(layered) => layered.resolve(&name),
+            None => layered::resolve(&name),
+        }
+        .ok_or(Error::MissingEnv(name))
+    }
+
+    /// Gets the value of an environment variable and parses it into a type.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    ///
+    /// # Returns
+    /// A parsed value of the environment variable.
+    pub fn get_parse<T: FromStr>(&self, name: impl AsRef<str>) -> Result<T> {
+        let name = name.as_ref();
+        let val = self.get(name)?;
+        val.parse::<T>().map_err(|_| self.format_error(name, &val))
+    }
+
+    /// Reads key-value pairs from a specified environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs.
+    ///
+    /// # Returns
+    /// Key-value pairs in the order of the variable with their indexes,
+    /// or an error pointing at the malformed pair.
+    fn read_keys_with(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<Vec<(usize, String, String)>> {
+        let name = name.as_ref();
+        // Read keys from environment
+        let keys = self.get(name)?;
+        if keys.is_empty() {
+            return Err(Error::EmptyEnv(self.full_name(name)));
+        }
+
+        format.split(&self.full_name(name), &keys)
+    }
+
+    /// Retrieves a set of key-value pairs from an environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// A `HashMap<String, String>` representing the key-value pairs extracted
+    /// from the environment variable.
+    #[inline]
+    pub fn get_keys(&self, name: impl AsRef<str>) -> Result<HashMap<String, String>> {
+        self.get_keys_with(name, &KeysFormat::default())
+    }
+
+    /// Retrieves a set of key-value pairs in the format from an environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs, e.g. with other separators.
+    ///
+    /// # Returns
+    /// A `HashMap<String, String>` representing the key-value pairs extracted
+    /// from the environment variable.
+    pub fn get_keys_with(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<HashMap<String, String>> {
+        let pairs = self.read_keys_with(name, format)?;
+        Ok(pairs
+            .into_iter()
+            .map(|(_, key, value)| (key, value))
+            .collect())
+    }
+
+    /// Retrieves a set of key-value pairs from an environment variable and parses the values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// A `HashMap<String, T>` representing the key-value pairs extracted
+    /// from the environment variable, with values parsed into the type `T`.
+    #[inline]
+    pub fn get_keys_parse<T: FromStr>(&self, name: impl AsRef<str>) -> Result<HashMap<String, T>> {
+        self.get_keys_parse_with(name, &KeysFormat::default())
+    }
+
+    /// Retrieves a set of key-value pairs in the format from an environment variable
+    /// and parses the values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    /// - `format`: The grammar of the pairs, e.g. with other separators.
+    ///
+    /// # Returns
+    /// A `HashMap<String, T>` representing the key-value pairs extracted
+    /// from the environment variable, or `Error::WrongPair` with the index
+    /// of the value that can not be parsed.
+    pub fn get_keys_parse_with<T: FromStr>(
+        &self,
+        name: impl AsRef<str>,
+        format: &KeysFormat,
+    ) -> Result<HashMap<String, T>> {
+        let name = name.as_ref();
+        let mut hash_map = HashMap::new();
+
+        for (index, key, value) in self.read_keys_with(name, format)? {
+            let value = value
+                .parse::<T>()
+                .map_err(|_| self.pair_error(name, index, "value can not be parsed"))?;
+            hash_map.insert(key, value);
+        }
+
+        Ok(hash_map)
+    }
+
+    /// Returns `Error::WrongPair` for the pair at the index.
+    fn pair_error(&self, name: &str, index: usize, reason: &'static str) -> Error {
+        Error::WrongPair {
+            name: self.full_name(name),
+            index,
+            reason,
+        }
+    }
+
+    /// Returns `Error::EmptyEnv` for an empty value that failed to parse, `Error::WrongFormat` otherwise.
+    fn format_error(&self, name: &str, value: &str) -> Error {
+        match value.is_empty() {
+            true => Error::EmptyEnv(self.full_name(name)),
+            false => Error::WrongFormat(self.full_name(name)),
+        }
+    }
+}
+
+impl From<Layered> for Envs {
+    #[inline]
+    fn from(layered: Layered) -> Envs {
+        Envs::from_layered(layered)
+    }
+}
 
 /// Gets the value of an environment variable into a string.
 ///
+/// Variables are read from the resolver installed with `Layered::install`.
+/// Without it, variables missing in the process environment are looked up
+/// in the `.env` file installed with `dotenv::DotEnv::install`.
+///
 /// # Parameters
 /// - `name`: The name of the environment variable.
 ///
 /// # Returns
 /// A string representing the value of the environment variable.
-pub fn get(name: &'static str) -> Result<String> {
-    env::var(name).map_err(|_| Error::MissingEnv(name))
+#[inline]
+pub fn get(name: impl AsRef<str>) -> Result<String> {
+    Envs::global().get(name)
 }
 
-/// Gets the value of an environment variable and parses it into a type.
+/// Gets the value of an environment variable and the layer that supplied it.
 ///
 /// # Parameters
 /// - `name`: The name of the environment variable.
 ///
 /// # Returns
-/// A parsed value of the environment variable.
-pub fn get_parse<T: FromStr>(name: &'static str) -> Result<T> {
-    let val = get(name)?;
-    val.parse::<T>().map_err(|_| Error::WrongFormat(name))
+/// The value of the environment variable and its `Layer`.
+#[inline]
+pub fn get_with_layer(name: impl AsRef<str>) -> Result<(String, Layer)> {
+    Envs::global().get_with_layer(name)
 }
 
-/// Reads key-value pairs from a specified environment variable.
-///
-/// The environment variable should contain pairs in the format `key:value`,
-/// separated by commas. For example: `KEY1:VALUE1,KEY2:VALUE2`.
+/// Gets the value of an environment variable and parses it into a type.
 ///
 /// # Parameters
-/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `name`: The name of the environment variable.
 ///
 /// # Returns
-/// A `Result<Vec<(String, String)>>` containing a vector of tuples,
-/// where each tuple represents a key-value pair extracted from the
-/// environment variable. If the environment variable is not set
-/// or if the format is incorrect, an error is returned.
-fn read_keys(name: &'static str) -> Result<Vec<(String, String)>> {
-    // Read keys from environment
-    let keys = env::var(name).map_err(|_| Error::MissingEnv(name))?;
-
-    let mut result = Vec::new();
-
-    for pair in keys.split(',') {
-        let mut split = pair.split(':');
-        if let (Some(key), Some(value)) = (split.next(), split.next()) {
-            result.push((key.trim().to_string(), value.trim().to_string()));
-        } else {
-            return Err(Error::WrongFormat(name)); // Обработка ошибки, если пара некорректна
-        }
-    }
-
-    Ok(result)
+/// A parsed value of the environment variable.
+#[inline]
+pub fn get_parse<T: FromStr>(name: impl AsRef<str>) -> Result<T> {
+    Envs::global().get_parse(name)
 }
 
 /// Retrieves a set of key-value pairs from an environment variable.
 ///
 /// The environment variable should contain pairs in the format `key:value`,
 /// separated by commas. For example: `KEY1:VALUE1,KEY2:VALUE2`.
+/// See `KeysFormat` for quoting and escaping of separators.
 ///
 /// # Parameters
 /// - `name`: The name of the environment variable containing the key-value pairs.
@@ -84,14 +319,16 @@ fn read_keys(name: &'static str) -> Result<Vec<(String, String)>> {
 /// A `HashMap<String, String>` representing the key-value pairs extracted
 /// from the environment variable. If the environment variable is not set
 /// or if the format is incorrect, an error is returned.
-pub fn get_keys(name: &'static str) -> Result<HashMap<String, String>> {
-    Ok(read_keys(name)?.into_iter().collect())
+#[inline]
+pub fn get_keys(name: impl AsRef<str>) -> Result<HashMap<String, String>> {
+    Envs::global().get_keys(name)
 }
 
 /// Retrieves a set of key-value pairs from an environment variable and parses the values.
 ///
 /// The environment variable should contain pairs in the format `key:value`,
 /// separated by commas. For example: `KEY1:VALUE1,KEY2:VALUE2`.
+/// See `KeysFormat` for quoting and escaping of separators.
 ///
 /// # Parameters
 /// - `name`: The name of the environment variable containing the key-value pairs.
@@ -105,15 +342,44 @@ pub fn get_keys(name: &'static str) -> Result<HashMap<String, String>> {
 /// from the environment variable, with values parsed into the specified type `T`.
 /// If the environment variable is not set, if the format is incorrect, or if
 /// the values cannot be parsed into the specified type, an error is returned.
-pub fn get_keys_parse<T: FromStr>(name: &'static str) -> Result<HashMap<String, T>> {
-    let mut hash_map = HashMap::new();
+#[inline]
+pub fn get_keys_parse<T: FromStr>(name: impl AsRef<str>) -> Result<HashMap<String, T>> {
+    Envs::global().get_keys_parse(name)
+}
 
-    for (key, value) in read_keys(name)? {
-        let value = value.parse::<T>().map_err(|_| Error::WrongFormat(name))?;
-        hash_map.insert(key, value);
-    }
+/// Retrieves a set of key-value pairs in the format from an environment variable.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `format`: The grammar of the pairs, e.g. with other separators.
+///
+/// # Returns
+/// A `HashMap<String, String>` representing the key-value pairs extracted
+/// from the environment variable.
+#[inline]
+pub fn get_keys_with(
+    name: impl AsRef<str>,
+    format: &KeysFormat,
+) -> Result<HashMap<String, String>> {
+    Envs::global().get_keys_with(name, format)
+}
 
-    Ok(hash_map)
+/// Retrieves a set of key-value pairs in the format from an environment variable
+/// and parses the values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+/// - `format`: The grammar of the pairs, e.g. with other separators.
+///
+/// # Returns
+/// A `HashMap<String, T>` representing the key-value pairs extracted
+/// from the environment variable, with values parsed into the type `T`.
+#[inline]
+pub fn get_keys_parse_with<T: FromStr>(
+    name: impl AsRef<str>,
+    format: &KeysFormat,
+) -> Result<HashMap<String, T>> {
+    Envs::global().get_keys_parse_with(name, format)
 }
 
 // region:    --- Error
@@ -121,14 +387,110 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
-    MissingEnv(&'static str),
-    WrongFormat(&'static str),
+    MissingEnv(String),
+    /// Variable is set, but its value is empty.
+    EmptyEnv(String),
+    WrongFormat(String),
+    #[cfg(all(
+        feature = "cuuid",
+        any(feature = "b58", feature = "b64", feature = "b32")
+    ))]
+    WrongScheme(String, crate::cuuid::Error),
+    /// Configuration file can not be read.
+    FileRead(String, std::io::Error),
+    /// Configuration file is invalid at the line.
+    FileParse {
+        file: String,
+        line: usize,
+        reason: &'static str,
+    },
+    /// Key-value pair at the index is malformed.
+    WrongPair {
+        name: String,
+        index: usize,
+        reason: &'static str,
+    },
+    /// Item of a list at the index is malformed.
+    WrongItem {
+        name: String,
+        index: usize,
+        reason: &'static str,
+    },
+    /// Key is defined several times, `index` is of its repeated pair.
+    DuplicateKey {
+        name: String,
+        key: String,
+        index: usize,
+    },
+    /// All errors collected by `Collector`.
+    Multiple(Vec<Error>),
+}
+
+impl Error {
+    /// Returns the name of the variable that caused the error.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Error::MissingEnv(name) | Error::EmptyEnv(name) | Error::WrongFormat(name) => {
+                Some(name)
+            }
+            Error::WrongPair { name, .. }
+            | Error::WrongItem { name, .. }
+            | Error::DuplicateKey { name, .. } => Some(name),
+            #[cfg(all(
+                feature = "cuuid",
+                any(feature = "b58", feature = "b64", feature = "b32")
+            ))]
+            Error::WrongScheme(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Turns `Error::MissingEnv` into `Ok(None)` for optional getters.
+    fn missing_as_none<T>(self) -> Result<Option<T>> {
+        match self {
+            Error::MissingEnv(_) => Ok(None),
+            err => Err(err),
+        }
+    }
 }
 
 // region:    --- Error Boilerplate
 impl core::fmt::Display for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Error::MissingEnv(name) => write!(fmt, "`{name}` is not set"),
+            Error::EmptyEnv(name) => write!(fmt, "`{name}` is set but empty"),
+            Error::WrongFormat(name) => write!(fmt, "`{name}` has a wrong format"),
+            #[cfg(all(
+                feature = "cuuid",
+                any(feature = "b58", feature = "b64", feature = "b32")
+            ))]
+            Error::WrongScheme(name, err) => write!(fmt, "`{name}` has a wrong scheme: {err}"),
+            Error::WrongPair {
+                name,
+                index,
+                reason,
+            } => write!(fmt, "`{name}` has a wrong pair at index {index}: {reason}"),
+            Error::WrongItem {
+                name,
+                index,
+                reason,
+            } => write!(fmt, "`{name}` has a wrong item at index {index}: {reason}"),
+            Error::DuplicateKey { name, key, index } => {
+                write!(fmt, "`{name}` has a duplicate key `{key}` at index {index}")
+            }
+            Error::FileRead(file, err) => write!(fmt, "Failed to read `{file}`: {err}"),
+            Error::FileParse { file, line, reason } => {
+                write!(fmt, "Invalid `{file}` at line {line}: {reason}")
+            }
+            Error::Multiple(errors) => {
+                write!(fmt, "Invalid configuration, {} errors:", errors.len())?;
+                for err in errors {
+                    write!(fmt, "\n  - {err}")?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
@@ -140,71 +502,129 @@ impl std::error::Error for Error {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
 
     #[test]
     fn test_get() {
-        // Установим переменную окружения для теста
-        env::set_var("TEST_VAR", "test_value");
+        // Переменные окружения для теста без изменения окружения процесса
+        let envs = Envs::from_map([("TEST_VAR", "test_value")]);
 
         // Получаем значение переменной окружения
-        let result = get("TEST_VAR");
+        let result = envs.get("TEST_VAR");
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "test_value");
 
         // Проверяем отсутствие переменной окружения
-        let result = get("NON_EXISTENT_VAR");
+        let result = envs.get("NON_EXISTENT_VAR");
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_get_dynamic_name() {
+        let envs = Envs::from_map([("TENANT_1_URL", "first"), ("TENANT_2_URL", "second")]);
+
+        // Имена переменных, собранные во время выполнения
+        for (tenant, expected) in [(1, "first"), (2, "second")] {
+            let name = format!("TENANT_{tenant}_URL");
+            assert_eq!(envs.get(&name).ok().as_deref(), Some(expected));
+        }
+
+        let result = envs.get(format!("TENANT_{}_URL", 3));
+        assert!(matches!(result, Err(Error::MissingEnv(name)) if name == "TENANT_3_URL"));
+    }
+
+    #[test]
+    fn test_get_with_layer() {
+        // Слой окружения процесса без изменения окружения процесса
+        let envs = Envs::from_layered(
+            Layered::new()
+                .with(
+                    Layer::Process,
+                    HashMap::from([("TEST_LAYER_VAR".to_string(), "test_value".to_string())]),
+                )
+                .with_defaults([("TEST_LAYER_DEFAULT", "default")]),
+        );
+        let result = envs.get_with_layer("TEST_LAYER_VAR");
+        assert_eq!(
+            result.ok(),
+            Some(("test_value".to_string(), Layer::Process))
+        );
+        let result = envs.get_with_layer("TEST_LAYER_DEFAULT");
+        assert_eq!(result.ok(), Some(("default".to_string(), Layer::Default)));
+
+        // Значение из переданного набора переменных
+        let envs = Envs::from_map([("TEST_LAYER_VAR", "map_value")]);
+        let result = envs.get_with_layer("TEST_LAYER_VAR");
+        assert_eq!(
+            result.ok(),
+            Some(("map_value".to_string(), Layer::Override))
+        );
+
+        // Проверяем отсутствие переменной окружения
+        let result = get_with_layer("NON_EXISTENT_LAYER_VAR");
+        assert!(matches!(result, Err(Error::MissingEnv(_))));
+    }
+
     #[test]
     fn test_get_parse() {
-        // Установим переменную окружения для теста
-        env::set_var("TEST_INT", "42");
+        let envs = Envs::from_map([("TEST_INT", "42"), ("TEST_INVALID", "not_a_number")]);
 
         // Получаем и парсим значение переменной окружения
-        let result: Result<i32> = get_parse("TEST_INT");
+        let result: Result<i32> = envs.get_parse("TEST_INT");
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 42);
 
         // Проверяем неправильный формат
-        env::set_var("TEST_INVALID", "not_a_number");
-        let result: Result<i32> = get_parse("TEST_INVALID");
+        let result: Result<i32> = envs.get_parse("TEST_INVALID");
         assert!(result.is_err());
     }
 
     #[test]
     fn test_get_keys() {
-        // Установим переменную окружения для теста
-        env::set_var("TEST_KEYS", "KEY1:VALUE1,KEY2:VALUE2");
+        let envs = Envs::from_map([("TEST_KEYS", "KEY1:VALUE1,KEY2:VALUE2")]);
 
         // Получаем ключи из переменной окружения
-        let result = get_keys("TEST_KEYS");
+        let result = envs.get_keys("TEST_KEYS");
         assert!(result.is_ok());
         let map = result.unwrap();
         assert_eq!(map.get("KEY1"), Some(&"VALUE1".to_string()));
         assert_eq!(map.get("KEY2"), Some(&"VALUE2".to_string()));
 
         // Проверяем отсутствие переменной окружения
-        let result = get_keys("NON_EXISTENT_KEYS");
+        let result = envs.get_keys("NON_EXISTENT_KEYS");
         assert!(result.is_err());
     }
 
     #[test]
     fn test_get_keys_parse() {
-        // Установим переменную окружения для теста
-        env::set_var("TEST_KEYS_PARSE", "KEY1:1,KEY2:2");
+        let envs = Envs::from_map([
+            ("TEST_KEYS_PARSE", "KEY1:1,KEY2:2"),
+            ("TEST_INVALID_KEYS", "KEY1:not_a_number"),
+        ]);
 
         // Получаем и парсим ключи из переменной окружения
-        let result: Result<HashMap<String, i32>> = get_keys_parse("TEST_KEYS_PARSE");
+        let result: Result<HashMap<String, i32>> = envs.get_keys_parse("TEST_KEYS_PARSE");
         assert!(result.is_ok());
         let map = result.unwrap();
         assert_eq!(map.get("KEY1"), Some(&1));
         assert_eq!(map.get("KEY2"), Some(&2));
 
         // Проверяем неправильный формат
-        env::set_var("TEST_INVALID_KEYS", "KEY1:not_a_number");
-        let result: Result<HashMap<String, i32>> = get_keys_parse("TEST_INVALID_KEYS");
+        let result: Result<HashMap<String, i32>> = envs.get_keys_parse("TEST_INVALID_KEYS");
         assert!(result.is_err());
+
+        // Ошибка указывает на индекс пары
+        let envs = Envs::from_map([("TEST_INVALID_PAIR", "KEY1:1,KEY2:x")]);
+        let result: Result<HashMap<String, i32>> = envs.get_keys_parse("TEST_INVALID_PAIR");
+        assert!(matches!(result, Err(Error::WrongPair { index: 1, .. })));
+
+        // Другие разделители
+        let envs = Envs::from_map([("TEST_KEYS_FORMAT", "KEY1=1;KEY2=2")]);
+        let format = KeysFormat::new().pair_separator(';').key_separator('=');
+        let result: Result<HashMap<String, i32>> =
+            envs.get_keys_parse_with("TEST_KEYS_FORMAT", &format);
+        assert_eq!(
+            result.ok().and_then(|map| map.get("KEY2").copied()),
+            Some(2)
+        );
     }
 }
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::{Envs, Error, Result};
+
+impl Envs {
+    /// Gets the value of an optional environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    ///
+    /// # Returns
+    /// * `Ok(None)` if the variable is not set.
+    /// * `Ok(Some(value))` if it is set, the value may be empty.
+    pub fn get_opt(&self, name: impl AsRef<str>) -> Result<Option<String>> {
+        self.get(name).map(Some).or_else(Error::missing_as_none)
+    }
+
+    /// Gets the value of an environment variable, or the default if it is not set.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    /// - `default`: The value used if the variable is not set.
+    pub fn get_or(&self, name: impl AsRef<str>, default: impl Into<String>) -> String {
+        self.get(name).unwrap_or_else(|_| default.into())
+    }
+
+    /// Gets the value of an environment variable, or computes the default if it is not set.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    /// - `default`: The function computing the value if the variable is not set.
+    pub fn get_or_else(&self, name: impl AsRef<str>, default: impl FnOnce() -> String) -> String {
+        self.get(name).unwrap_or_else(|_| default())
+    }
+
+    /// Gets the value of an optional environment variable and parses it into a type.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    ///
+    /// # Returns
+    /// * `Ok(None)` if the variable is not set.
+    /// * `Err(Error::EmptyEnv)` if it is set but empty and an empty value can not be parsed.
+    /// * `Err(Error::WrongFormat)` if it can not be parsed.
+    pub fn get_parse_opt<T: FromStr>(&self, name: impl AsRef<str>) -> Result<Option<T>> {
+        self.get_parse(name)
+            .map(Some)
+            .or_else(Error::missing_as_none)
+    }
+
+    /// Gets the value of an environment variable and parses it into a type,
+    /// or returns the default if it is not set.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable.
+    /// - `default`: The value used if the variable is not set.
+    ///
+    /// # Returns
+    /// The parsed value or the default, errors are the same as of `get_parse_opt`.
+    pub fn get_parse_or<T: FromStr>(&self, name: impl AsRef<str>, default: T) -> Result<T> {
+        Ok(self.get_parse_opt(name)?.unwrap_or(default))
+    }
+
+    /// Retrieves an optional set of key-value pairs from an environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// * `Ok(None)` if the variable is not set.
+    /// * `Err(Error::EmptyEnv)` if it is set but empty.
+    /// * `Err(Error::WrongPair)` with the index of a malformed pair.
+    /// * `Err(Error::DuplicateKey)` if a key is defined several times.
+    pub fn get_keys_opt(&self, name: impl AsRef<str>) -> Result<Option<HashMap<String, String>>> {
+        self.get_keys(name)
+            .map(Some)
+            .or_else(Error::missing_as_none)
+    }
+
+    /// Retrieves an optional set of key-value pairs from an environment variable
+    /// and parses the values.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the environment variable containing the key-value pairs.
+    ///
+    /// # Returns
+    /// * `Ok(None)` if the variable is not set.
+    /// * `Err(Error::EmptyEnv)` if it is set but empty.
+    /// * `Err(Error::WrongPair)` with the index of a malformed pair or of a value that can not be parsed.
+    /// * `Err(Error::DuplicateKey)` if a key is defined several times.
+    pub fn get_keys_parse_opt<T: FromStr>(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<HashMap<String, T>>> {
+        self.get_keys_parse(name)
+            .map(Some)
+            .or_else(Error::missing_as_none)
+    }
+}
+
+/// Gets the value of an optional environment variable.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+///
+/// # Returns
+/// * `Ok(None)` if the variable is not set.
+/// * `Ok(Some(value))` if it is set, the value may be empty.
+#[inline]
+pub fn get_opt(name: impl AsRef<str>) -> Result<Option<String>> {
+    Envs::global().get_opt(name)
+}
+
+/// Gets the value of an environment variable, or the default if it is not set.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+/// - `default`: The value used if the variable is not set.
+#[inline]
+pub fn get_or(name: impl AsRef<str>, default: impl Into<String>) -> String {
+    Envs::global().get_or(name, default)
+}
+
+/// Gets the value of an environment variable, or computes the default if it is not set.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+/// - `default`: The function computing the value if the variable is not set.
+#[inline]
+pub fn get_or_else(name: impl AsRef<str>, default: impl FnOnce() -> String) -> String {
+    Envs::global().get_or_else(name, default)
+}
+
+/// Gets the value of an optional environment variable and parses it into a type.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+///
+/// # Returns
+/// * `Ok(None)` if the variable is not set.
+/// * `Err(Error::EmptyEnv)` if it is set but empty and an empty value can not be parsed.
+/// * `Err(Error::WrongFormat)` if it can not be parsed.
+#[inline]
+pub fn get_parse_opt<T: FromStr>(name: impl AsRef<str>) -> Result<Option<T>> {
+    Envs::global().get_parse_opt(name)
+}
+
+/// Gets the value of an environment variable and parses it into a type,
+/// or returns the default if it is not set.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable.
+/// - `default`: The value used if the variable is not set.
+#[inline]
+pub fn get_parse_or<T: FromStr>(name: impl AsRef<str>, default: T) -> Result<T> {
+    Envs::global().get_parse_or(name, default)
+}
+
+/// Retrieves an optional set of key-value pairs from an environment variable.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+///
+/// # Returns
+/// * `Ok(None)` if the variable is not set.
+/// * `Err(Error::EmptyEnv)` if it is set but empty.
+/// * `Err(Error::WrongPair)` with the index of a malformed pair.
+/// * `Err(Error::DuplicateKey)` if a key is defined several times.
+#[inline]
+pub fn get_keys_opt(name: impl AsRef<str>) -> Result<Option<HashMap<String, String>>> {
+    Envs::global().get_keys_opt(name)
+}
+
+/// Retrieves an optional set of key-value pairs from an environment variable
+/// and parses the values.
+///
+/// # Parameters
+/// - `name`: The name of the environment variable containing the key-value pairs.
+///
+/// # Returns
+/// * `Ok(None)` if the variable is not set.
+/// * `Err(Error::EmptyEnv)` if it is set but empty.
+/// * `Err(Error::WrongPair)` with the index of a malformed pair or of a value that can not be parsed.
+/// * `Err(Error::DuplicateKey)` if a key is defined several times.
+#[inline]
+pub fn get_keys_parse_opt<T: FromStr>(name: impl AsRef<str>) -> Result<Option<HashMap<String, T>>> {
+    Envs::global().get_keys_parse_opt(name)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envs() -> Envs {
+        Envs::from_map([
+            ("OPT_INT", "42"),
+            ("OPT_EMPTY", ""),
+            ("OPT_INVALID", "not_a_number"),
+            ("OPT_KEYS", "KEY1:1,KEY2:2"),
+        ])
+    }
+
+    #[test]
+    fn test_get_opt() -> Result<()> {
+        let envs = envs();
+
+        assert_eq!(envs.get_opt("OPT_INT")?.as_deref(), Some("42"));
+        assert_eq!(envs.get_opt("OPT_EMPTY")?.as_deref(), Some(""));
+        assert_eq!(envs.get_opt("OPT_MISSING")?, None);
+
+        assert_eq!(envs.get_or("OPT_MISSING", "default"), "default");
+        assert_eq!(envs.get_or("OPT_INT", "default"), "42");
+        assert_eq!(
+            envs.get_or_else("OPT_MISSING", || "computed".into()),
+            "computed"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_parse_opt() -> Result<()> {
+        let envs = envs();
+
+        assert_eq!(envs.get_parse_opt::<i32>("OPT_INT")?, Some(42));
+        assert_eq!(envs.get_parse_opt::<i32>("OPT_MISSING")?, None);
+        assert_eq!(envs.get_parse_or("OPT_MISSING", 8080)?, 8080);
+        assert_eq!(envs.get_parse_or("OPT_INT", 8080)?, 42);
+
+        // Unset, set but empty and set but malformed are different
+        let result = envs.get_parse_opt::<i32>("OPT_EMPTY");
+        assert!(matches!(result, Err(Error::EmptyEnv(name)) if name == "OPT_EMPTY"));
+
+        let result = envs.get_parse_or("OPT_INVALID", 8080);
+        assert!(matches!(result, Err(Error::WrongFormat(name)) if name == "OPT_INVALID"));
+
+        // Empty string is still a valid `String`
+        assert_eq!(
+            envs.get_parse_opt::<String>("OPT_EMPTY")?.as_deref(),
+            Some("")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_keys_opt() -> Result<()> {
+        let envs = envs();
+
+        let keys = envs
+            .get_keys_parse_opt::<u8>("OPT_KEYS")?
+            .unwrap_or_default();
+        assert_eq!(keys.get("KEY2"), Some(&2));
+        assert_eq!(envs.get_keys_opt("OPT_MISSING")?, None);
+        assert!(matches!(
+            envs.get_keys_opt("OPT_EMPTY"),
+            Err(Error::EmptyEnv(_))
+        ));
+        assert!(matches!(
+            envs.get_keys_opt("OPT_INVALID"),
+            Err(Error::WrongPair { index: 0, .. })
+        ));
+
+        Ok(())
+    }
+}
+
+// endregion: --- Tests
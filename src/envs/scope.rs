@@ -0,0 +1,91 @@
+use core::ops::Deref;
+
+use super::Envs;
+
+/// Reader of environment variables sharing a name prefix, e.g. `BILLING_`.
+///
+/// Dereferences to `Envs`, so all getters are available and read
+/// `BILLING_DB_URL` for `scope.get("DB_URL")`. Errors contain the full name.
+#[derive(Debug, Clone)]
+pub struct Scope {
+    envs: Envs,
+}
+
+impl Scope {
+    /// Creates a scope of the global environment.
+    #[inline]
+    pub fn new(prefix: impl AsRef<str>) -> Scope {
+        Envs::global().scope(prefix)
+    }
+
+    /// Creates a nested scope, its prefix is appended to the prefix of this scope.
+    #[inline]
+    pub fn sub(&self, prefix: impl AsRef<str>) -> Scope {
+        self.envs.scope(prefix)
+    }
+
+    /// Returns the full prefix of the scope.
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.envs.prefix
+    }
+
+    #[inline]
+    pub(super) fn from_envs(envs: Envs) -> Scope {
+        Scope { envs }
+    }
+}
+
+impl Deref for Scope {
+    type Target = Envs;
+
+    #[inline]
+    fn deref(&self) -> &Envs {
+        &self.envs
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::envs::{Error, Result};
+
+    #[test]
+    fn test_scope() -> Result<()> {
+        let envs = Envs::from_map([
+            ("BILLING_PORT", "8080"),
+            ("BILLING_DB_URL", "postgres://billing"),
+            ("AUTH_DB_URL", "postgres://auth"),
+        ]);
+
+        let billing = envs.scope("BILLING_");
+        assert_eq!(billing.prefix(), "BILLING_");
+        assert_eq!(billing.get_parse::<u16>("PORT")?, 8080);
+
+        let db = billing.sub("DB_");
+        assert_eq!(db.prefix(), "BILLING_DB_");
+        assert_eq!(db.get("URL")?, "postgres://billing");
+        assert_eq!(
+            envs.scope("AUTH_").sub("DB_").get("URL")?,
+            "postgres://auth"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scope_errors() {
+        let envs = Envs::from_map([("BILLING_PORT", "not_a_number")]);
+        let billing = envs.scope("BILLING_");
+
+        let result = billing.sub("DB_").get("URL");
+        assert!(matches!(result, Err(Error::MissingEnv(name)) if name == "BILLING_DB_URL"));
+
+        let result = billing.get_parse::<u16>("PORT");
+        assert!(matches!(result, Err(Error::WrongFormat(name)) if name == "BILLING_PORT"));
+    }
+}
+
+// endregion: --- Tests
@@ -0,0 +1,73 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "cuuid",
+    feature = "b58",
+    feature = "b64"
+))]
+
+#[cfg(feature = "test-util")]
+use grapple_utils::cuuid::deterministic::{self, Generator};
+use grapple_utils::cuuid::uuid::{Uuid, Version};
+use grapple_utils::cuuid::{CUuid, CUuidId, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CUuidId)]
+struct UserId(Uuid);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CUuidId)]
+#[cuuid(scheme = "b58", strategy = "v4")]
+struct OrderId {
+    id: Uuid,
+}
+
+fn get_v7() -> Uuid {
+    Uuid::parse_str("01970370-2e2d-76e3-8c69-3bb5e943b2a2").unwrap()
+}
+
+#[test]
+fn test_display_from_str() -> Result<(), Error> {
+    let user_id = UserId::from(get_v7());
+
+    assert_eq!(CUuid::B64.from(get_v7()), user_id.to_string());
+    assert_eq!(user_id.to_string().parse::<UserId>()?, user_id);
+    assert!("not an id".parse::<UserId>().is_err());
+
+    let order_id = OrderId::from(get_v7());
+    assert_eq!(CUuid::B58.from(get_v7()), order_id.to_string());
+    assert_eq!(Uuid::from(order_id), get_v7());
+
+    Ok(())
+}
+
+#[test]
+fn test_strategy() {
+    assert_eq!(
+        UserId::new().as_uuid().get_version(),
+        Some(Version::SortRand)
+    );
+    assert_eq!(
+        OrderId::new().as_uuid().get_version(),
+        Some(Version::Random)
+    );
+}
+
+#[test]
+#[cfg(feature = "test-util")]
+fn test_deterministic() {
+    let expected = Generator::new(5).now_v7();
+
+    let _guard = deterministic::install(Generator::new(5));
+    assert_eq!(UserId::new(), UserId::from(expected));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde() -> Result<(), serde_json::Error> {
+    let user_id = UserId::from(get_v7());
+
+    let json = serde_json::to_string(&user_id)?;
+    assert_eq!(json, format!("\"{user_id}\""));
+    assert_eq!(serde_json::from_str::<UserId>(&json)?, user_id);
+    assert!(serde_json::from_str::<UserId>("\"!\"").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+#![cfg(all(feature = "derive", feature = "envs", feature = "b58", feature = "b64"))]
+
+use std::collections::HashMap;
+
+use grapple_utils::envs::{self, FromEnv};
+
+#[derive(Debug, FromEnv)]
+struct Config {
+    derive_string: String,
+    #[env(name = "DERIVE_PORT_NUMBER", default = "8080")]
+    port: u16,
+    #[env(keys)]
+    derive_keys: HashMap<String, u8>,
+    #[env(encoding = "b64u", bytes)]
+    derive_secret: Vec<u8>,
+    #[env(name = "DERIVE_SECRET", encoding = "b64u")]
+    secret_text: String,
+    #[env(keys, encoding = "b58")]
+    derive_encoded_keys: HashMap<String, String>,
+    #[env(name = "DERIVE_ENCODED_KEYS", keys, encoding = "b58", bytes)]
+    encoded_key_bytes: std::collections::HashMap<String, std::vec::Vec<u8>>,
+    #[env(optional)]
+    derive_missing: Option<f64>,
+    #[env(keys, default)]
+    derive_missing_keys: HashMap<String, String>,
+}
+
+#[derive(Debug, FromEnv)]
+struct Required {
+    #[allow(dead_code)]
+    derive_required: String,
+}
+
+/// Fields named as locals of the generated code.
+#[derive(Debug, FromEnv)]
+struct Shadowing {
+    envs: String,
+    collector: u8,
+    #[env(default = "1")]
+    value: u8,
+    #[env(name = "ENVS")]
+    field_envs: String,
+}
+
+/// Fields named with raw identifiers.
+#[derive(Debug, FromEnv)]
+struct RawIdents {
+    r#type: String,
+    #[env(default = "3")]
+    r#match: u8,
+}
+
+#[test]
+fn test_load_from_defaults() -> envs::Result<()> {
+    let envs = envs::Envs::from_map([
+        ("DERIVE_STRING", "value"),
+        ("DERIVE_KEYS", "a:1,b:2"),
+        ("DERIVE_SECRET", "SGVsbG8"),
+        ("DERIVE_ENCODED_KEYS", "KEY:9Ajdvzr"),
+    ]);
+
+    let config = Config::load_from(&envs)?;
+
+    assert_eq!(config.derive_string, "value");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.derive_keys.get("b"), Some(&2));
+    assert_eq!(config.derive_secret, b"Hello");
+    assert_eq!(config.secret_text, "Hello");
+    assert_eq!(config.derive_encoded_keys["KEY"], "Hello");
+    assert_eq!(config.encoded_key_bytes["KEY"], b"Hello");
+    assert_eq!(config.derive_missing, None);
+    assert!(config.derive_missing_keys.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_load_from() -> envs::Result<()> {
+    let envs = envs::Envs::from_map([
+        ("DERIVE_STRING", "map"),
+        ("DERIVE_PORT_NUMBER", "9090"),
+        ("DERIVE_KEYS", "a:1"),
+        ("DERIVE_SECRET", "SGVsbG8"),
+        ("DERIVE_ENCODED_KEYS", "KEY:9Ajdvzr"),
+        ("DERIVE_MISSING", "1.5"),
+    ]);
+
+    let config = Config::load_from(&envs)?;
+
+    assert_eq!(config.derive_string, "map");
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.derive_missing, Some(1.5));
+
+    Ok(())
+}
+
+#[test]
+fn test_load_shadowing_fields() -> envs::Result<()> {
+    let envs = envs::Envs::from_map([("ENVS", "envs"), ("COLLECTOR", "2")]);
+
+    let config = Shadowing::load_from(&envs)?;
+
+    assert_eq!(config.envs, "envs");
+    assert_eq!(config.collector, 2);
+    assert_eq!(config.value, 1);
+    assert_eq!(config.field_envs, "envs");
+
+    Ok(())
+}
+
+#[test]
+fn test_load_raw_idents() -> envs::Result<()> {
+    let envs = envs::Envs::from_map([("TYPE", "raw")]);
+
+    let config = RawIdents::load_from(&envs)?;
+
+    assert_eq!(config.r#type, "raw");
+    assert_eq!(config.r#match, 3);
+
+    // Names are read without the `r#` prefix
+    let result = RawIdents::load_from(&envs::Envs::from_map([("R#TYPE", "raw")]));
+    assert!(
+        matches!(result, Err(envs::Error::Multiple(errors)) if matches!(&errors[..], [envs::Error::MissingEnv(name)] if name == "TYPE"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_load_from_env_missing() {
+    let result = Required::load_from_env();
+
+    // All errors are reported together
+    match result {
+        Err(envs::Error::Multiple(errors)) => {
+            assert!(
+                matches!(&errors[..], [envs::Error::MissingEnv(name)] if name == "DERIVE_REQUIRED")
+            );
+        }
+        other => panic!("Unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn test_load_from_all_errors() {
+    let envs = envs::Envs::from_map([
+        ("DERIVE_PORT_NUMBER", "port"),
+        ("DERIVE_KEYS", "a:1"),
+        ("DERIVE_SECRET", "SGVsbG8"),
+        ("DERIVE_MISSING", "not_a_number"),
+    ]);
+
+    let Err(envs::Error::Multiple(errors)) = Config::load_from(&envs) else {
+        panic!("Expected all errors");
+    };
+
+    let names: Vec<_> = errors.iter().filter_map(envs::Error::name).collect();
+    assert_eq!(
+        names,
+        [
+            "DERIVE_STRING",
+            "DERIVE_PORT_NUMBER",
+            "DERIVE_ENCODED_KEYS",
+            "DERIVE_ENCODED_KEYS",
+            "DERIVE_MISSING"
+        ]
+    );
+}